@@ -0,0 +1,192 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use project_zyheeda_pathfinding::{
+	components::compute_path_method::{
+		a_star::{AStar, OpenList},
+		bidirectional_a_star::BidirectionalAStar,
+		lazy_theta_star::LazyThetaStar,
+		theta_star::ThetaStar,
+	},
+	traits::{
+		computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+		compute_path::{ComputePath, NewComputer},
+		heuristic::{landmarks::Landmarks, Manhattan},
+	},
+};
+use std::collections::{HashMap, HashSet};
+
+const SIZE: i32 = 512;
+const ROOM: i32 = 64;
+
+struct Map {
+	grid: ComputeGrid,
+	obstacles: Obstacles,
+	start: ComputeGridNode,
+	end: ComputeGridNode,
+}
+
+/// Roughly an eighth of all nodes blocked, the same on every run
+fn scattered() -> Map {
+	let grid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(SIZE - 1, SIZE - 1),
+	};
+	let start = ComputeGridNode::new(0, 0);
+	let end = ComputeGridNode::new(SIZE - 1, SIZE - 1);
+	let mut obstacles = Obstacles::new(grid);
+	let mut seed = 42_u64;
+
+	for x in 0..SIZE {
+		for y in 0..SIZE {
+			seed = seed
+				.wrapping_mul(6364136223846793005)
+				.wrapping_add(1442695040888963407);
+			if !(seed >> 33).is_multiple_of(8) {
+				continue;
+			}
+			obstacles.insert(ComputeGridNode::new(x, y));
+		}
+	}
+
+	obstacles.remove(&start);
+	obstacles.remove(&end);
+	Map {
+		grid,
+		obstacles,
+		start,
+		end,
+	}
+}
+
+fn corridor() -> Map {
+	let grid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(SIZE - 1, ROOM - 1),
+	};
+	let middle = ROOM / 2;
+	let walls = (ROOM..SIZE - ROOM)
+		.flat_map(|x| (0..ROOM).map(move |y| ComputeGridNode::new(x, y)))
+		.filter(|node| node.y != middle);
+
+	Map {
+		grid,
+		obstacles: Obstacles::from_nodes(grid, walls),
+		start: ComputeGridNode::new(0, 0),
+		end: ComputeGridNode::new(SIZE - 1, ROOM - 1),
+	}
+}
+
+fn search<TMethod>(c: &mut Criterion, name: &str, map: Map)
+where
+	TMethod: NewComputer + ComputePath,
+{
+	let Map {
+		grid,
+		obstacles,
+		start,
+		end,
+	} = map;
+	let method = TMethod::new(grid, obstacles);
+	let (expanded, path) = method.path_with_expansions(start, end);
+
+	assert!(!path.is_empty());
+	if let Some(expanded) = expanded {
+		println!("{name}: {expanded} nodes expanded");
+	}
+	c.bench_function(name, |b| b.iter(|| method.path(start, end)));
+}
+
+fn a_star(c: &mut Criterion) {
+	search::<AStar>(c, "a_star 512x512", scattered());
+	search::<AStar>(c, "a_star corridor", corridor());
+}
+
+/// [`AStar`] before dense storage, kept as baseline
+fn hashed_a_star(
+	grid: ComputeGrid,
+	obstacles: &HashSet<ComputeGridNode>,
+	start: ComputeGridNode,
+	end: ComputeGridNode,
+) -> Vec<ComputeGridNode> {
+	let mut open = OpenList::new(start, end, Manhattan);
+	let mut parents = HashMap::from([(start, start)]);
+	let mut g_scores = HashMap::from([(start, 0.)]);
+
+	while let Some(current) = open.pop_lowest_f() {
+		if current == end {
+			let mut path = vec![current];
+			let mut node = current;
+			while node != start {
+				node = parents[&node];
+				path.push(node);
+			}
+			path.reverse();
+			return path;
+		}
+
+		for (x, y) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
+			let neighbor = ComputeGridNode::new(current.x + x, current.y + y);
+
+			if !grid.contains(neighbor) || obstacles.contains(&neighbor) {
+				continue;
+			}
+
+			let g = g_scores[&current] + 1.;
+
+			if g >= g_scores.get(&neighbor).copied().unwrap_or(f32::INFINITY) {
+				continue;
+			}
+
+			open.push(neighbor, g);
+			parents.insert(neighbor, current);
+			g_scores.insert(neighbor, g);
+		}
+	}
+
+	vec![]
+}
+
+fn a_star_hashed(c: &mut Criterion) {
+	let Map {
+		grid,
+		obstacles,
+		start,
+		end,
+	} = scattered();
+	let obstacles = obstacles.iter().collect::<HashSet<_>>();
+	let path = hashed_a_star(grid, &obstacles, start, end);
+
+	assert_eq!(
+		AStar::<Manhattan>::new(grid, Obstacles::from_nodes(grid, obstacles.iter().copied()))
+			.path(start, end)
+			.len(),
+		path.len()
+	);
+	c.bench_function("a_star_hashed 512x512", |b| {
+		b.iter(|| hashed_a_star(grid, &obstacles, start, end))
+	});
+}
+
+fn a_star_landmarks(c: &mut Criterion) {
+	search::<AStar<Landmarks>>(c, "a_star_landmarks 512x512", scattered());
+	search::<AStar<Landmarks>>(c, "a_star_landmarks corridor", corridor());
+}
+
+fn bidirectional_a_star(c: &mut Criterion) {
+	search::<BidirectionalAStar>(c, "bidirectional_a_star 512x512", scattered());
+	search::<BidirectionalAStar>(c, "bidirectional_a_star corridor", corridor());
+}
+
+fn theta_star(c: &mut Criterion) {
+	search::<ThetaStar>(c, "theta_star 512x512", scattered());
+}
+
+fn lazy_theta_star(c: &mut Criterion) {
+	search::<LazyThetaStar>(c, "lazy_theta_star 512x512", scattered());
+}
+
+criterion_group! {
+	name = benches;
+	config = Criterion::default().sample_size(10);
+	targets = a_star, a_star_hashed, a_star_landmarks, bidirectional_a_star, theta_star, lazy_theta_star
+}
+criterion_main!(benches);
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use project_zyheeda_pathfinding::{
+	assets::chunked_grid::ChunkedGrid,
+	components::{
+		chunked_tile_grid::ChunkedTileGrid,
+		compute_path_method::theta_star::ThetaStar,
+		player_camera::PlayerCamera,
+	},
+	plugins::{
+		chunked_grid::ChunkedGridPlugin,
+		editor::EditorPlugin,
+		pathfinding::PathfindingPlugin,
+	},
+	systems::spawn::Spawn,
+};
+
+fn main() -> AppExit {
+	let mut app = App::new();
+
+	app.add_plugins((
+		DefaultPlugins,
+		PathfindingPlugin::<ChunkedGrid, ThetaStar>::default(),
+		EditorPlugin::<ChunkedGrid>::default(),
+		ChunkedGridPlugin::<PlayerCamera>::default(),
+	))
+	.add_systems(Startup, ChunkedTileGrid::spawn);
+
+	app.run()
+}
@@ -1,2 +1,3 @@
+pub mod chunked_grid;
 pub mod collider_definition;
 pub mod grid;
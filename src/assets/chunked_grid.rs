@@ -0,0 +1,82 @@
+use crate::{
+	components::grid_context::GridContext,
+	traits::{
+		computable_grid::{ComputeGridNode, GetComputeGridNode, GetTranslation},
+		into_component::IntoComponent,
+	},
+};
+use bevy::prelude::*;
+
+/// An unbounded grid, whose tiles are loaded in chunks of `chunk_size` by `chunk_size`
+/// nodes. Chunks within `load_distance` chunks around the camera are kept loaded.
+#[derive(Asset, TypePath, Debug, PartialEq)]
+pub struct ChunkedGrid {
+	pub chunk_size: u32,
+	pub load_distance: u32,
+	pub scale: f32,
+}
+
+impl ChunkedGrid {
+	const DEFAULT: ChunkedGrid = ChunkedGrid {
+		chunk_size: 16,
+		load_distance: 1,
+		scale: 1.,
+	};
+}
+
+impl Default for ChunkedGrid {
+	fn default() -> Self {
+		Self::DEFAULT
+	}
+}
+
+impl IntoComponent for Handle<ChunkedGrid> {
+	type TComponent = GridContext<ChunkedGrid>;
+
+	fn into_component(self) -> Self::TComponent {
+		GridContext::from_handle(self)
+	}
+}
+
+impl GetComputeGridNode for ChunkedGrid {
+	fn compute_grid_node(&self, translation: Vec2) -> Option<ComputeGridNode> {
+		let Vec2 { x, y } = (translation / self.scale).round();
+
+		Some(ComputeGridNode::new(x as i32, y as i32))
+	}
+}
+
+impl GetTranslation for ChunkedGrid {
+	fn translation(&self, ComputeGridNode { x, y }: ComputeGridNode) -> Option<Vec2> {
+		Some(Vec2::new(x as f32, y as f32) * self.scale)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn get_compute_node_of_negative_translation() {
+		let grid = ChunkedGrid {
+			scale: 10.,
+			..default()
+		};
+
+		let node = grid.compute_grid_node(Vec2::new(-24., 16.));
+
+		assert_eq!(Some(ComputeGridNode::new(-2, 2)), node);
+	}
+
+	#[test]
+	fn get_translation_scaled() {
+		let grid = ChunkedGrid {
+			scale: 10.,
+			..default()
+		};
+
+		let translation = grid.translation(ComputeGridNode::new(-2, 3));
+
+		assert_eq!(Some(Vec2::new(-20., 30.)), translation);
+	}
+}
@@ -1,11 +1,35 @@
+#[cfg(feature = "app")]
+pub mod agent;
+#[cfg(feature = "app")]
+pub mod chunked_tile_grid;
+#[cfg(feature = "app")]
 pub mod clickable;
 pub mod compute_path_method;
+#[cfg(feature = "app")]
 pub mod computed_path;
+#[cfg(feature = "app")]
 pub mod despawn;
+#[cfg(feature = "app")]
+pub mod flow_field;
+#[cfg(feature = "app")]
+pub mod grid_chunks;
+#[cfg(feature = "app")]
 pub mod grid_context;
+#[cfg(feature = "app")]
+pub mod multi_agent_plan;
+#[cfg(feature = "app")]
+pub mod path_stats;
+#[cfg(feature = "app")]
 pub mod player_camera;
+#[cfg(feature = "app")]
 pub mod tile;
+#[cfg(feature = "app")]
 pub mod tile_collider;
+#[cfg(feature = "app")]
 pub mod tile_grid;
+#[cfg(feature = "app")]
 pub mod tile_type;
+#[cfg(feature = "app")]
 pub mod use_asset;
+#[cfg(feature = "app")]
+pub mod waypoint_label;
@@ -0,0 +1,442 @@
+use super::{computed_path::ComputedPath, multi_agent_plan::MultiAgentPlan, use_asset::UseAsset};
+use bevy::prelude::*;
+use std::path::Path;
+
+type NewPaths<'w, 's> =
+	Query<'w, 's, (&'static ComputedPath, &'static Parent), Added<ComputedPath>>;
+type Plans<'w, 's> = Query<'w, 's, (), With<MultiAgentPlan>>;
+
+#[derive(Component, Debug, PartialEq, Default)]
+#[require(
+	Transform,
+	Visibility,
+	UseAsset<Mesh>(Self::asset),
+	UseAsset<ColorMaterial>(Self::asset)
+)]
+pub struct Agent {
+	speed: f32,
+	route: Vec<Vec2>,
+	next: usize,
+}
+
+impl Agent {
+	const Z: f32 = 2.;
+
+	fn asset<TAsset>() -> UseAsset<TAsset>
+	where
+		TAsset: Asset,
+	{
+		UseAsset::new(Path::new("agent.json"))
+	}
+
+	/// Spawns an agent at the start of the first path computed for a grid.
+	///
+	/// `speed` is measured in world units per second. Paths of [`MultiAgentPlan`]s are
+	/// ignored.
+	pub fn spawn_on_path_start(
+		speed: f32,
+	) -> impl Fn(Commands, NewPaths, Plans, Query<&Parent, With<Self>>) {
+		move |mut commands, paths, plans, agents| {
+			for (ComputedPath { path, .. }, grid) in &paths {
+				if plans.contains(grid.get()) {
+					continue;
+				}
+				if agents.iter().any(|agent_grid| agent_grid == grid) {
+					continue;
+				}
+				let Some(start) = path.first() else {
+					continue;
+				};
+				let Some(mut grid) = commands.get_entity(grid.get()) else {
+					continue;
+				};
+
+				grid.with_child((
+					Agent {
+						speed,
+						route: path.iter().map(|node| node.xy()).collect(),
+						next: 0,
+					},
+					Transform::from_translation(start.with_z(Self::Z)),
+				));
+			}
+		}
+	}
+
+	pub fn follow_new_paths(
+		paths: NewPaths,
+		plans: Plans,
+		mut agents: Query<(&mut Self, &Transform, &Parent)>,
+	) {
+		for (ComputedPath { path, .. }, grid) in &paths {
+			if plans.contains(grid.get()) {
+				continue;
+			}
+			for (mut agent, transform, agent_grid) in &mut agents {
+				if agent_grid != grid {
+					continue;
+				}
+
+				agent.retarget(transform.translation.xy(), path);
+			}
+		}
+	}
+
+	/// Continue on the segment of the new path, that is closest to the current position.
+	fn retarget(&mut self, position: Vec2, path: &[Vec3]) {
+		self.route = path.iter().map(|node| node.xy()).collect();
+		self.next = (1..self.route.len())
+			.min_by(|a, b| {
+				let a = distance_to_segment(position, self.route[a - 1], self.route[*a]);
+				let b = distance_to_segment(position, self.route[b - 1], self.route[*b]);
+				a.total_cmp(&b)
+			})
+			.unwrap_or_default();
+	}
+
+	pub fn walk(time: Res<Time>, mut agents: Query<(&mut Self, &mut Transform)>) {
+		for (mut agent, mut transform) in &mut agents {
+			let mut distance = agent.speed * time.delta_secs();
+
+			while let Some(target) = agent.route.get(agent.next).copied() {
+				let offset = target - transform.translation.xy();
+				let length = offset.length();
+
+				if length > distance {
+					transform.translation += (offset / length * distance).extend(0.);
+					transform.look_to(Vec3::Z, offset.extend(0.));
+					break;
+				}
+
+				if length > 0. {
+					transform.look_to(Vec3::Z, offset.extend(0.));
+				}
+
+				transform.translation = target.extend(transform.translation.z);
+				distance -= length;
+				agent.next += 1;
+			}
+		}
+	}
+}
+
+fn distance_to_segment(position: Vec2, a: Vec2, b: Vec2) -> f32 {
+	let segment = b - a;
+	let length_squared = segment.length_squared();
+
+	if length_squared == 0. {
+		return position.distance(a);
+	}
+
+	let t = ((position - a).dot(segment) / length_squared).clamp(0., 1.);
+	position.distance(a + segment * t)
+}
+
+#[cfg(test)]
+mod test_spawn {
+	use super::*;
+	use crate::{assert_count, test_tools::SingleThreaded};
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+		app.add_systems(Update, Agent::spawn_on_path_start(42.));
+
+		app
+	}
+
+	fn is<TComponent>(entity: &EntityRef) -> bool
+	where
+		TComponent: Component,
+	{
+		entity.contains::<TComponent>()
+	}
+
+	#[test]
+	fn spawn_agent_at_path_start() {
+		let mut app = setup();
+		let grid = app.world_mut().spawn_empty().id();
+		app.world_mut()
+			.spawn(ComputedPath {
+				path: vec![Vec3::new(1., 2., 1.), Vec3::new(3., 4., 1.)],
+				..default()
+			})
+			.set_parent(grid);
+
+		app.update();
+
+		let [agent] = assert_count!(1, app.world().iter_entities().filter(is::<Agent>));
+		assert_eq!(
+			(
+				Some(&Agent {
+					speed: 42.,
+					route: vec![Vec2::new(1., 2.), Vec2::new(3., 4.)],
+					next: 0,
+				}),
+				Some(&Transform::from_xyz(1., 2., Agent::Z)),
+				Some(grid),
+			),
+			(
+				agent.get::<Agent>(),
+				agent.get::<Transform>(),
+				agent.get::<Parent>().map(Parent::get),
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_spawn_agent_for_empty_path() {
+		let mut app = setup();
+		let grid = app.world_mut().spawn_empty().id();
+		app.world_mut()
+			.spawn(ComputedPath::default())
+			.set_parent(grid);
+
+		app.update();
+
+		assert_count!(0, app.world().iter_entities().filter(is::<Agent>));
+	}
+
+	#[test]
+	fn spawn_only_one_agent_per_grid() {
+		let mut app = setup();
+		let grid = app.world_mut().spawn_empty().id();
+		app.world_mut()
+			.spawn(ComputedPath {
+				path: vec![Vec3::new(1., 2., 1.)],
+				..default()
+			})
+			.set_parent(grid);
+
+		app.update();
+		app.world_mut()
+			.spawn(ComputedPath {
+				path: vec![Vec3::new(1., 2., 1.)],
+				..default()
+			})
+			.set_parent(grid);
+		app.update();
+
+		assert_count!(1, app.world().iter_entities().filter(is::<Agent>));
+	}
+
+	#[test]
+	fn do_not_spawn_agent_for_paths_of_multi_agent_plan() {
+		let mut app = setup();
+		let plan = app.world_mut().spawn(MultiAgentPlan::default()).id();
+		app.world_mut()
+			.spawn(ComputedPath {
+				path: vec![Vec3::new(1., 2., 1.)],
+				..default()
+			})
+			.set_parent(plan);
+
+		app.update();
+
+		assert_count!(0, app.world().iter_entities().filter(is::<Agent>));
+	}
+}
+
+#[cfg(test)]
+mod test_follow {
+	use super::*;
+	use crate::test_tools::SingleThreaded;
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+		app.add_systems(Update, Agent::follow_new_paths);
+
+		app
+	}
+
+	#[test]
+	fn continue_on_closest_segment_of_new_path() {
+		let mut app = setup();
+		let grid = app.world_mut().spawn_empty().id();
+		let agent = app
+			.world_mut()
+			.spawn((Agent::default(), Transform::from_xyz(5., 1., Agent::Z)))
+			.set_parent(grid)
+			.id();
+		app.world_mut()
+			.spawn(ComputedPath {
+				path: vec![
+					Vec3::new(0., 0., 1.),
+					Vec3::new(0., 5., 1.),
+					Vec3::new(10., 0., 1.),
+					Vec3::new(10., 10., 1.),
+				],
+				..default()
+			})
+			.set_parent(grid);
+
+		app.update();
+
+		assert_eq!(
+			Some(2),
+			app.world().entity(agent).get::<Agent>().map(|a| a.next)
+		);
+	}
+
+	#[test]
+	fn ignore_paths_of_other_grids() {
+		let mut app = setup();
+		let grid = app.world_mut().spawn_empty().id();
+		let other = app.world_mut().spawn_empty().id();
+		let agent = app
+			.world_mut()
+			.spawn((Agent::default(), Transform::default()))
+			.set_parent(grid)
+			.id();
+		app.world_mut()
+			.spawn(ComputedPath {
+				path: vec![Vec3::new(0., 0., 1.)],
+				..default()
+			})
+			.set_parent(other);
+
+		app.update();
+
+		assert_eq!(
+			Some(&Agent::default()),
+			app.world().entity(agent).get::<Agent>()
+		);
+	}
+
+	#[test]
+	fn ignore_paths_of_multi_agent_plans() {
+		let mut app = setup();
+		let plan = app.world_mut().spawn(MultiAgentPlan::default()).id();
+		let agent = app
+			.world_mut()
+			.spawn((Agent::default(), Transform::default()))
+			.set_parent(plan)
+			.id();
+		app.world_mut()
+			.spawn(ComputedPath {
+				path: vec![Vec3::new(0., 0., 1.)],
+				..default()
+			})
+			.set_parent(plan);
+
+		app.update();
+
+		assert_eq!(
+			Some(&Agent::default()),
+			app.world().entity(agent).get::<Agent>()
+		);
+	}
+}
+
+#[cfg(test)]
+mod test_walk {
+	use super::*;
+	use crate::test_tools::SingleThreaded;
+	use std::time::Duration;
+
+	fn setup(delta: Duration) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut time = Time::<()>::default();
+
+		time.advance_by(delta);
+		app.insert_resource(time);
+		app.add_systems(Update, Agent::walk);
+
+		app
+	}
+
+	fn translation(app: &App, entity: Entity) -> Option<Vec3> {
+		app.world()
+			.entity(entity)
+			.get::<Transform>()
+			.map(|t| t.translation)
+	}
+
+	#[test]
+	fn move_towards_next_node_by_speed() {
+		let mut app = setup(Duration::from_millis(500));
+		let agent = app
+			.world_mut()
+			.spawn((
+				Agent {
+					speed: 2.,
+					route: vec![Vec2::new(0., 0.), Vec2::new(10., 0.)],
+					next: 1,
+				},
+				Transform::from_xyz(0., 0., 2.),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(Some(Vec3::new(1., 0., 2.)), translation(&app, agent));
+	}
+
+	#[test]
+	fn turn_at_node() {
+		let mut app = setup(Duration::from_secs(1));
+		let agent = app
+			.world_mut()
+			.spawn((
+				Agent {
+					speed: 3.,
+					route: vec![Vec2::new(0., 0.), Vec2::new(1., 0.), Vec2::new(1., 10.)],
+					next: 1,
+				},
+				Transform::from_xyz(0., 0., 2.),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			(Some(Vec3::new(1., 2., 2.)), Some(2)),
+			(
+				translation(&app, agent),
+				app.world().entity(agent).get::<Agent>().map(|a| a.next)
+			)
+		);
+	}
+
+	#[test]
+	fn stop_at_last_node() {
+		let mut app = setup(Duration::from_secs(10));
+		let agent = app
+			.world_mut()
+			.spawn((
+				Agent {
+					speed: 3.,
+					route: vec![Vec2::new(0., 0.), Vec2::new(1., 0.)],
+					next: 1,
+				},
+				Transform::from_xyz(0., 0., 2.),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(Some(Vec3::new(1., 0., 2.)), translation(&app, agent));
+	}
+
+	#[test]
+	fn face_walking_direction() {
+		let mut app = setup(Duration::from_millis(500));
+		let agent = app
+			.world_mut()
+			.spawn((
+				Agent {
+					speed: 2.,
+					route: vec![Vec2::new(0., 0.), Vec2::new(10., 0.)],
+					next: 1,
+				},
+				Transform::from_xyz(0., 0., 2.),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(Transform::from_xyz(1., 0., 2.).looking_to(Vec3::Z, Vec3::X)),
+			app.world().entity(agent).get::<Transform>().copied()
+		);
+	}
+}
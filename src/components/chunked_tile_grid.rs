@@ -0,0 +1,21 @@
+use super::{grid_chunks::GridChunks, use_asset::UseAsset};
+use crate::assets::chunked_grid::ChunkedGrid;
+use bevy::prelude::*;
+use std::path::Path;
+
+#[derive(Component, Debug, PartialEq, Default)]
+#[require(
+	Transform,
+	Visibility,
+	GridChunks,
+	UseAsset<ChunkedGrid>(ChunkedTileGrid::asset)
+)]
+pub struct ChunkedTileGrid;
+
+impl ChunkedTileGrid {
+	const ASSET_PATH: &str = "chunked_grid.json";
+
+	fn asset() -> UseAsset<ChunkedGrid> {
+		UseAsset::new(Path::new(Self::ASSET_PATH))
+	}
+}
@@ -172,6 +172,39 @@ impl GetKey for MouseRight {
 	}
 }
 
+#[derive(Debug, PartialEq, Default)]
+pub struct MouseMiddle;
+
+impl GetKey for MouseMiddle {
+	type TKey = MouseButton;
+
+	fn get_key() -> Self::TKey {
+		const { MouseButton::Middle }
+	}
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct KeyA;
+
+impl GetKey for KeyA {
+	type TKey = KeyCode;
+
+	fn get_key() -> Self::TKey {
+		const { KeyCode::KeyA }
+	}
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct KeyG;
+
+impl GetKey for KeyG {
+	type TKey = KeyCode;
+
+	fn get_key() -> Self::TKey {
+		const { KeyCode::KeyG }
+	}
+}
+
 #[cfg(test)]
 mod test_update {
 	use super::*;
@@ -964,3 +997,105 @@ mod test_switch_on_single {
 		);
 	}
 }
+
+#[cfg(test)]
+mod test_zoomed_camera {
+	use super::*;
+	use crate::{
+		assets::collider_definition::ColliderDefinition,
+		components::{player_camera::PlayerCamera, tile_collider::TileCollider},
+		new_handle,
+		test_tools::{primary_window, ComputeCameras, SingleThreaded},
+	};
+	use bevy::render::camera::camera_system;
+
+	fn setup(handle: &Handle<ColliderDefinition>, cursor: Vec2) -> App {
+		let mut app = App::new().single_threaded(Update).compute_cameras(Update);
+		let mut colliders = Assets::default();
+		let mut input = ButtonInput::<MouseButton>::default();
+
+		colliders.insert(
+			handle,
+			ColliderDefinition {
+				half_width: 15.,
+				half_height: 15.,
+			},
+		);
+		input.press(MouseButton::Left);
+		app.insert_resource(colliders);
+		app.insert_resource(input);
+		app.init_resource::<MouseWorldPosition>();
+		app.add_systems(
+			Update,
+			(
+				MouseWorldPosition::update_using::<PlayerCamera>,
+				Clickable::<MouseLeft>::detect_click_on::<TileCollider>,
+			)
+				.chain()
+				.after(camera_system::<OrthographicProjection>),
+		);
+		app.world_mut().spawn(primary_window(cursor));
+
+		app
+	}
+
+	fn camera(translation: Vec3, scale: f32) -> impl Bundle {
+		(
+			PlayerCamera,
+			OrthographicProjection {
+				scale,
+				..OrthographicProjection::default_2d()
+			},
+			Transform::from_translation(translation),
+			GlobalTransform::from_translation(translation),
+		)
+	}
+
+	fn tile(handle: &Handle<ColliderDefinition>, x: f32, y: f32) -> impl Bundle {
+		(
+			Clickable::<MouseLeft>::default(),
+			TileCollider(handle.clone()),
+			Transform::from_xyz(x, y, 0.),
+		)
+	}
+
+	#[test]
+	fn click_tile_under_cursor_when_zoomed_out() {
+		let handle = new_handle!(ColliderDefinition);
+		let mut app = setup(&handle, Vec2::new(600., 200.));
+		app.world_mut().spawn(camera(Vec3::new(10., 20., 0.), 2.));
+		let hit = app.world_mut().spawn(tile(&handle, 420., 210.)).id();
+		let missed = app.world_mut().spawn(tile(&handle, 210., 120.)).id();
+
+		app.update();
+
+		assert_eq!(
+			[Some(true), Some(false)],
+			[hit, missed].map(|tile| app
+				.world()
+				.entity(tile)
+				.get::<Clickable<MouseLeft>>()
+				.map(Clickable::is_clicked))
+		);
+	}
+
+	#[test]
+	fn click_tile_under_cursor_when_zoomed_in() {
+		let handle = new_handle!(ColliderDefinition);
+		let mut app = setup(&handle, Vec2::new(600., 200.));
+		app.world_mut().spawn(camera(Vec3::new(10., 20., 0.), 0.25));
+		let hit = app.world_mut().spawn(tile(&handle, 60., 45.)).id();
+		let missed = app.world_mut().spawn(tile(&handle, 210., 120.)).id();
+
+		app.update();
+
+		assert_eq!(
+			[Some(true), Some(false)],
+			[hit, missed].map(|tile| app
+				.world()
+				.entity(tile)
+				.get::<Clickable<MouseLeft>>()
+				.map(Clickable::is_clicked))
+		);
+	}
+}
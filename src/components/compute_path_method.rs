@@ -1,27 +1,48 @@
 pub mod a_star;
+pub mod anytime_a_star;
+pub mod bidirectional_a_star;
+pub mod conflict_based_search;
+pub mod cooperative_a_star;
+pub mod lazy_theta_star;
 pub mod straight_line;
 pub mod straight_line_wide;
 pub mod theta_star;
+pub mod weighted_a_star;
 
+#[cfg(feature = "app")]
 use super::{
-	computed_path::ComputedPath,
-	despawn::Despawn,
+	computed_path::{ComputedPath, ComputedRoute},
 	grid_context::GridContext,
+	path_stats::PathStats,
 	tile_type::{TileType, TileTypeValue},
 };
-use crate::traits::{
-	computable_grid::{GetComputeGridNode, GetTranslation},
-	compute_path::{ComputePath, NewComputer},
+#[cfg(feature = "app")]
+use crate::{
+	events::{path_request::PathRequest, path_result::PathResult},
+	resources::expansion_budget::ExpansionBudget,
+	traits::{
+		computable_grid::{ComputeGridNode, GetComputeGridNode, GetTranslation},
+		compute_path::{ComputePath, ComputePathSliced, NewComputer, SearchProgress},
+	},
 };
-use bevy::prelude::*;
-use std::marker::PhantomData;
+#[cfg(feature = "app")]
+use bevy::{
+	ecs::query::QueryFilter,
+	prelude::*,
+	tasks::{block_on, poll_once, AsyncComputeTaskPool, Task},
+	utils::{Duration, Instant},
+};
+#[cfg(feature = "app")]
+use std::{marker::PhantomData, sync::Arc};
 
+#[cfg(feature = "app")]
 #[derive(Component, Debug, PartialEq)]
 pub struct ComputePathMethod<TGrid, TMethod> {
-	method: TMethod,
+	method: Arc<TMethod>,
 	_p: PhantomData<TGrid>,
 }
 
+#[cfg(feature = "app")]
 impl<TGrid, TMethod> ComputePathMethod<TGrid, TMethod>
 where
 	TGrid: Asset + Sync + Send + 'static,
@@ -29,7 +50,7 @@ where
 {
 	fn new(method: TMethod) -> Self {
 		Self {
-			method,
+			method: Arc::new(method),
 			_p: PhantomData,
 		}
 	}
@@ -51,153 +72,651 @@ where
 		}
 	}
 
-	#[allow(clippy::too_many_arguments)]
-	/* FIXME: This system does too much. It would probably be a good idea to move
-	 *        start and end tile detection to a separate system.
-	 */
 	pub fn compute_path(
 		mut commands: Commands,
+		mut requests: EventReader<PathRequest>,
+		mut results: EventWriter<PathResult>,
+		grids: Res<Assets<TGrid>>,
+		computers: Query<(&Self, &GridContext<TGrid>)>,
+		pending: Query<(Entity, &PendingRequest), With<PendingPath<TMethod>>>,
+	) where
+		TGrid: GetComputeGridNode,
+		TMethod: ComputePath,
+	{
+		for request in requests.read() {
+			if !request.options.accepts::<TMethod>() {
+				continue;
+			}
+			let Ok((computer, context)) = computers.get(request.grid) else {
+				continue;
+			};
+
+			PendingRequest::cancel(&mut commands, request.requester, request.grid, &pending);
+
+			let Some(route) = Self::route_nodes(context, &grids, &request.route()) else {
+				results.send(PathResult {
+					request: request.clone(),
+					path: vec![],
+				});
+				continue;
+			};
+
+			commands.spawn((
+				PendingRequest(request.clone()),
+				PendingPath::<TMethod>::spawn(&computer.method, route),
+			));
+		}
+	}
+
+	pub fn poll_path(
+		mut commands: Commands,
+		mut results: EventWriter<PathResult>,
 		grids: Res<Assets<TGrid>>,
-		computers: Query<(Entity, &Self, &GridContext<TGrid>)>,
+		computers: Query<(&Self, &GridContext<TGrid>)>,
+		mut pending: Query<(Entity, &PendingRequest, &mut PendingPath<TMethod>)>,
 		computed_paths: Query<(Entity, &Parent), With<ComputedPath>>,
-		tiles: Query<(Entity, &Transform, Ref<TileType>)>,
-		mut removed_tiles: RemovedComponents<TileType>,
-		mut start: Local<Option<(Entity, Vec2)>>,
-		mut end: Local<Option<(Entity, Vec2)>>,
 	) where
-		TGrid: GetComputeGridNode + GetTranslation,
+		TGrid: GetTranslation,
 		TMethod: ComputePath,
 	{
-		let removed = removed_tiles.read().collect::<Vec<_>>();
-		if !tiles.iter().any(|(.., tile_type)| tile_type.is_changed()) && removed.is_empty() {
+		for (entity, PendingRequest(request), mut pending) in &mut pending {
+			let Some(computation) = block_on(poll_once(&mut pending.task)) else {
+				continue;
+			};
+
+			commands.entity(entity).despawn();
+
+			let Ok((computer, context)) = computers.get(request.grid) else {
+				continue;
+			};
+
+			computer.send_result(
+				&mut commands,
+				&mut results,
+				grids.get(&context.handle),
+				&computed_paths,
+				request,
+				computation,
+			);
+		}
+	}
+
+	pub fn compute_path_sliced(
+		mut commands: Commands,
+		mut requests: EventReader<PathRequest>,
+		mut results: EventWriter<PathResult>,
+		grids: Res<Assets<TGrid>>,
+		computers: Query<&GridContext<TGrid>, With<Self>>,
+		pending: Query<(Entity, &PendingRequest), With<SlicedPath<TMethod>>>,
+	) where
+		TGrid: GetComputeGridNode,
+		TMethod: ComputePathSliced,
+	{
+		for request in requests.read() {
+			if !request.options.accepts::<TMethod>() {
+				continue;
+			}
+			let Ok(context) = computers.get(request.grid) else {
+				continue;
+			};
+
+			PendingRequest::cancel(&mut commands, request.requester, request.grid, &pending);
+
+			let Some(route) = Self::route_nodes(context, &grids, &request.route()) else {
+				results.send(PathResult {
+					request: request.clone(),
+					path: vec![],
+				});
+				continue;
+			};
+
+			commands.spawn((
+				PendingRequest(request.clone()),
+				SlicedPath::<TMethod>::new(route),
+			));
+		}
+	}
+
+	/// Anytime searches send a [`PathResult`] for each improved path.
+	#[allow(clippy::too_many_arguments)]
+	pub fn continue_sliced_path(
+		mut commands: Commands,
+		mut results: EventWriter<PathResult>,
+		grids: Res<Assets<TGrid>>,
+		budget: Res<ExpansionBudget>,
+		computers: Query<(&Self, &GridContext<TGrid>)>,
+		mut pending: Query<(Entity, &PendingRequest, &mut SlicedPath<TMethod>)>,
+		computed_paths: Query<(Entity, &Parent), With<ComputedPath>>,
+		mut first: Local<usize>,
+	) where
+		TGrid: GetTranslation,
+		TMethod: ComputePath + ComputePathSliced,
+	{
+		let mut entities = pending
+			.iter()
+			.map(|(entity, ..)| entity)
+			.collect::<Vec<_>>();
+		if entities.is_empty() {
 			return;
 		}
 
-		Self::update_start_and_end(&mut start, &mut end, tiles, removed);
+		// rotate who is served first, so no request starves when the frame budget runs out
+		*first %= entities.len();
+		entities.rotate_left(*first);
+		*first += 1;
 
-		match (*start, *end) {
-			(Some((_, start)), Some((_, end))) => {
-				for (entity, computer, context) in &computers {
-					let path = computer.get_path(context, &grids, start, end);
-					computer.spawn_path(&mut commands, entity, path, &computed_paths);
-				}
+		let mut remaining = budget.per_frame;
+		let anytime_limit = budget.per_anytime_search;
+
+		for entity in entities {
+			if remaining == 0 {
+				return;
 			}
-			_ => {
-				for (entity, ..) in &computers {
-					Self::despawn_path(&mut commands, entity, &computed_paths);
-				}
+			let Ok((entity, PendingRequest(request), mut sliced)) = pending.get_mut(entity) else {
+				continue;
+			};
+			let Ok((computer, context)) = computers.get(request.grid) else {
+				commands.entity(entity).despawn();
+				continue;
+			};
+
+			let budget = budget.per_request.min(remaining);
+			let (expanded, computation) = sliced.advance(&*computer.method, budget, anytime_limit);
+			remaining = remaining.saturating_sub(expanded);
+
+			if sliced.done {
+				commands.entity(entity).despawn();
 			}
+
+			let Some(computation) = computation else {
+				continue;
+			};
+
+			computer.send_result(
+				&mut commands,
+				&mut results,
+				grids.get(&context.handle),
+				&computed_paths,
+				request,
+				computation,
+			);
 		}
 	}
 
-	fn get_path(
-		&self,
+	fn route_nodes(
 		context: &GridContext<TGrid>,
 		grids: &Assets<TGrid>,
-		start: Vec2,
-		end: Vec2,
-	) -> Option<Vec<Vec3>>
+		route: &[Vec2],
+	) -> Option<Vec<ComputeGridNode>>
 	where
-		TGrid: GetComputeGridNode + GetTranslation,
-		TMethod: ComputePath,
+		TGrid: GetComputeGridNode,
 	{
 		let grid = grids.get(&context.handle)?;
-		let start = grid.compute_grid_node(start)?;
-		let end = grid.compute_grid_node(end)?;
-		let path = self
-			.method
-			.path(start, end)
-			.into_iter()
-			.filter_map(|node| grid.translation(node))
-			.map(|translation| translation.extend(1.))
-			.collect::<Vec<_>>();
 
-		Some(path)
+		route
+			.iter()
+			.map(|translation| grid.compute_grid_node(*translation))
+			.collect()
 	}
 
-	fn update_start_and_end(
-		start: &mut Local<Option<(Entity, Vec2)>>,
-		end: &mut Local<Option<(Entity, Vec2)>>,
-		tiles: Query<(Entity, &Transform, Ref<TileType>)>,
-		removed_tiles: Vec<Entity>,
-	) {
-		for (entity, transform, tile_type) in &tiles {
-			if !tile_type.is_changed() {
-				continue;
-			}
-
-			if **tile_type == TileTypeValue::Start {
-				**start = Some((entity, transform.translation.xy()));
-			} else if matches!(**start, Some((start, _)) if start == entity) {
-				**start = None;
-			}
-
-			if **tile_type == TileTypeValue::End {
-				**end = Some((entity, transform.translation.xy()));
-			} else if matches!(**end, Some((end, _)) if end == entity) {
-				**end = None;
-			}
-		}
+	fn send_result(
+		&self,
+		commands: &mut Commands,
+		results: &mut EventWriter<PathResult>,
+		grid: Option<&TGrid>,
+		computed_paths: &Query<(Entity, &Parent), With<ComputedPath>>,
+		request: &PathRequest,
+		computation: Computation,
+	) where
+		TGrid: GetTranslation,
+		TMethod: ComputePath,
+	{
+		let Some(grid) = grid else {
+			results.send(PathResult {
+				request: request.clone(),
+				path: vec![],
+			});
+			return;
+		};
+		let path = computation
+			.path
+			.iter()
+			.filter_map(|node| grid.translation(*node))
+			.collect::<Vec<_>>();
 
-		if matches!(**start, Some((entity, _)) if removed_tiles.contains(&entity) ) {
-			**start = None;
+		if request.options.draw {
+			self.spawn_path(commands, request.grid, &path, computation, computed_paths);
 		}
 
-		if matches!(**end, Some((entity, _)) if removed_tiles.contains(&entity) ) {
-			**end = None;
-		}
+		results.send(PathResult {
+			request: request.clone(),
+			path,
+		});
 	}
 
 	fn spawn_path(
 		&self,
 		commands: &mut Commands,
 		entity: Entity,
-		path: Option<Vec<Vec3>>,
+		path: &[Vec2],
+		computation: Computation,
 		computed_paths: &Query<(Entity, &Parent), With<ComputedPath>>,
 	) where
 		TMethod: ComputePath,
 	{
-		let Some(path) = path else {
+		ComputedPath::despawn_of(commands, entity, computed_paths);
+
+		let Some(mut entity) = commands.get_entity(entity) else {
 			return;
 		};
+		entity.with_child((
+			ComputedPath {
+				path: path
+					.iter()
+					.map(|translation| translation.extend(1.))
+					.collect(),
+				draw_connections: self.method.draw_connections(),
+			},
+			PathStats::new::<TMethod>(
+				&computation.path,
+				path,
+				computation.expanded,
+				computation.duration,
+				computation.bound,
+			),
+			ComputedRoute {
+				route: computation.route,
+				path: computation.path,
+			},
+		));
+	}
+}
 
-		Self::despawn_path(commands, entity, computed_paths);
+/// Despawning the entity cancels its computation.
+#[cfg(feature = "app")]
+#[derive(Component, Debug, PartialEq)]
+pub struct PendingRequest(pub PathRequest);
 
-		let Some(mut entity) = commands.get_entity(entity) else {
-			return;
+#[cfg(feature = "app")]
+impl PendingRequest {
+	pub fn cancel<TFilter>(
+		commands: &mut Commands,
+		requester: Entity,
+		grid: Entity,
+		pending: &Query<(Entity, &PendingRequest), TFilter>,
+	) where
+		TFilter: QueryFilter,
+	{
+		for (entity, PendingRequest(request)) in pending {
+			if request.requester != requester || request.grid != grid {
+				continue;
+			}
+			let Some(entity) = commands.get_entity(entity) else {
+				continue;
+			};
+			entity.despawn_recursive();
+		}
+	}
+}
+
+#[cfg(feature = "app")]
+#[derive(Component)]
+pub struct PendingPath<TMethod> {
+	task: Task<Computation>,
+	_p: PhantomData<TMethod>,
+}
+
+#[cfg(feature = "app")]
+impl<TMethod> PendingPath<TMethod>
+where
+	TMethod: ComputePath + Sync + Send + 'static,
+{
+	fn spawn(method: &Arc<TMethod>, route: Vec<ComputeGridNode>) -> Self {
+		let method = method.clone();
+
+		Self {
+			task: AsyncComputeTaskPool::get().spawn(async move {
+				let start = Instant::now();
+				let (expanded, path) = chain_legs(&*method, &route);
+
+				Computation {
+					route,
+					path,
+					expanded,
+					duration: start.elapsed(),
+					bound: method.suboptimality_bound(),
+				}
+			}),
+			_p: PhantomData,
+		}
+	}
+}
+
+#[cfg(feature = "app")]
+#[derive(Component)]
+pub struct SlicedPath<TMethod>
+where
+	TMethod: ComputePathSliced + Sync + Send + 'static,
+{
+	route: Vec<ComputeGridNode>,
+	leg: usize,
+	path: Vec<ComputeGridNode>,
+	search: Option<TMethod::TSearch>,
+	expanded: usize,
+	duration: Duration,
+	/// Whether a path was returned, that the search is still improving
+	provisional: bool,
+	done: bool,
+}
+
+#[cfg(feature = "app")]
+impl<TMethod> SlicedPath<TMethod>
+where
+	TMethod: ComputePathSliced + Sync + Send + 'static,
+{
+	fn new(route: Vec<ComputeGridNode>) -> Self {
+		Self {
+			route,
+			leg: 0,
+			path: vec![],
+			search: None,
+			expanded: 0,
+			duration: Duration::ZERO,
+			provisional: false,
+			done: false,
+		}
+	}
+
+	/// After `anytime_limit` expansions, an anytime search settles with its latest path.
+	fn advance(
+		&mut self,
+		method: &TMethod,
+		budget: usize,
+		anytime_limit: usize,
+	) -> (usize, Option<Computation>)
+	where
+		TMethod: ComputePath,
+	{
+		let start = Instant::now();
+		let progress = self.advance_legs(method, budget);
+
+		self.duration += start.elapsed();
+
+		let (used, path, bound) = match progress {
+			SearchProgress::Pending { expanded } => (expanded, None, None),
+			SearchProgress::Improved {
+				expanded,
+				path,
+				bound,
+			} => {
+				self.provisional = true;
+				(expanded, Some(path), Some(bound))
+			}
+			SearchProgress::Done { expanded, path } => {
+				self.done = true;
+				// anytime searches only finish, once their path is optimal
+				let bound = match self.provisional {
+					true => Some(1.),
+					false => method.suboptimality_bound(),
+				};
+				(expanded, Some(path), bound)
+			}
 		};
-		entity.with_child(ComputedPath {
+
+		self.expanded += used;
+		if self.provisional && self.expanded >= anytime_limit {
+			self.done = true;
+		}
+
+		let computation = path.map(|path| Computation {
+			route: self.route.clone(),
 			path,
-			draw_connections: self.method.draw_connections(),
+			expanded: Some(self.expanded),
+			duration: self.duration,
+			bound,
 		});
+
+		(used, computation)
 	}
 
-	fn despawn_path(
-		commands: &mut Commands,
-		entity: Entity,
-		computed_paths: &Query<(Entity, &Parent), With<ComputedPath>>,
+	fn advance_legs(&mut self, method: &TMethod, budget: usize) -> SearchProgress {
+		let mut used = 0;
+
+		while let Some([start, end]) = self.route.get(self.leg..self.leg + 2) {
+			let last_leg = self.leg + 2 == self.route.len();
+			let search = self
+				.search
+				.get_or_insert_with(|| method.start_search(*start, *end));
+
+			let leg = match method.continue_search(search, budget - used) {
+				SearchProgress::Pending { expanded } => {
+					return SearchProgress::Pending {
+						expanded: used + expanded,
+					};
+				}
+				SearchProgress::Improved {
+					expanded,
+					path,
+					bound,
+				} if last_leg => {
+					let mut joined = self.path.clone();
+					let skip_joint = joined.last().is_some() && joined.last() == path.first();
+					joined.extend(path.into_iter().skip(skip_joint as usize));
+
+					return SearchProgress::Improved {
+						expanded: used + expanded,
+						path: joined,
+						bound,
+					};
+				}
+				// earlier legs are completed, so that the bound of the last leg holds for
+				// the whole path
+				SearchProgress::Improved { expanded, .. } => {
+					used += expanded;
+					continue;
+				}
+				SearchProgress::Done { expanded, path } => {
+					used += expanded;
+					path
+				}
+			};
+
+			self.search = None;
+			self.leg += 1;
+
+			if leg.is_empty() {
+				return SearchProgress::Done {
+					expanded: used,
+					path: vec![],
+				};
+			}
+
+			let skip_joint = self.path.last().is_some() && self.path.last() == leg.first();
+			self.path.extend(leg.into_iter().skip(skip_joint as usize));
+		}
+
+		SearchProgress::Done {
+			expanded: used,
+			path: std::mem::take(&mut self.path),
+		}
+	}
+}
+
+#[cfg(feature = "app")]
+struct Computation {
+	route: Vec<ComputeGridNode>,
+	path: Vec<ComputeGridNode>,
+	expanded: Option<usize>,
+	duration: Duration,
+	bound: Option<f32>,
+}
+
+/// Empty path, if any leg cannot be completed.
+#[cfg(feature = "app")]
+pub(crate) fn chain_legs<TMethod>(
+	method: &TMethod,
+	route: &[ComputeGridNode],
+) -> (Option<usize>, Vec<ComputeGridNode>)
+where
+	TMethod: ComputePath,
+{
+	let mut path = vec![];
+	let mut expanded = Some(0);
+
+	for leg in route.windows(2) {
+		let [start, end] = leg else {
+			continue;
+		};
+		let (leg_expanded, leg) = method.path_with_expansions(*start, *end);
+		expanded = expanded.zip(leg_expanded).map(|(a, b)| a + b);
+
+		if leg.is_empty() {
+			return (expanded, vec![]);
+		}
+
+		let skip_joint = path.last().is_some() && path.last() == leg.first();
+		path.extend(leg.into_iter().skip(skip_joint as usize));
+	}
+
+	(expanded, path)
+}
+
+#[cfg(feature = "app")]
+#[derive(Debug, Default)]
+pub struct PathMarkers {
+	start: Option<(Entity, Vec2)>,
+	end: Option<(Entity, Vec2)>,
+	waypoints: Vec<(Entity, Vec2)>,
+	agent_starts: Vec<(Entity, Vec2)>,
+	agent_goals: Vec<(Entity, Vec2)>,
+}
+
+#[cfg(feature = "app")]
+impl PathMarkers {
+	pub fn update(
+		&mut self,
+		tiles: Query<(Entity, &Transform, Ref<TileType>)>,
+		removed: Vec<Entity>,
 	) {
-		for (child, parent) in computed_paths {
-			if parent.get() != entity {
+		for (entity, transform, tile_type) in &tiles {
+			if !tile_type.is_changed() {
 				continue;
 			}
-			let Some(mut child) = commands.get_entity(child) else {
-				continue;
-			};
-			child.try_insert(Despawn::NextFrame);
+
+			let marker = (entity, transform.translation.xy());
+
+			Self::update_single(&mut self.start, marker, **tile_type == TileTypeValue::Start);
+			Self::update_single(&mut self.end, marker, **tile_type == TileTypeValue::End);
+
+			Self::update_ordered(
+				&mut self.waypoints,
+				marker,
+				**tile_type == TileTypeValue::Waypoint,
+			);
+			Self::update_ordered(
+				&mut self.agent_starts,
+				marker,
+				**tile_type == TileTypeValue::AgentStart,
+			);
+			Self::update_ordered(
+				&mut self.agent_goals,
+				marker,
+				**tile_type == TileTypeValue::AgentGoal,
+			);
+		}
+
+		if matches!(self.start, Some((entity, _)) if removed.contains(&entity)) {
+			self.start = None;
+		}
+
+		if matches!(self.end, Some((entity, _)) if removed.contains(&entity)) {
+			self.end = None;
 		}
+
+		for ordered in [
+			&mut self.waypoints,
+			&mut self.agent_starts,
+			&mut self.agent_goals,
+		] {
+			ordered.retain(|(entity, _)| !removed.contains(entity));
+		}
+	}
+
+	/// Updates the markers, returns `false` when no tile type changed or was removed.
+	pub fn update_on_change(
+		&mut self,
+		tiles: Query<(Entity, &Transform, Ref<TileType>)>,
+		removed_tiles: &mut RemovedComponents<TileType>,
+	) -> bool {
+		let removed = removed_tiles.read().collect::<Vec<_>>();
+		if !tiles.iter().any(|(.., tile_type)| tile_type.is_changed()) && removed.is_empty() {
+			return false;
+		}
+
+		self.update(tiles, removed);
+		true
+	}
+
+	fn update_single(
+		single: &mut Option<(Entity, Vec2)>,
+		(entity, translation): (Entity, Vec2),
+		is_single: bool,
+	) {
+		if is_single {
+			*single = Some((entity, translation));
+		} else if matches!(single, Some((single, _)) if single == &entity) {
+			*single = None;
+		}
+	}
+
+	/// Markers keep their position in the order when they change.
+	fn update_ordered(
+		ordered: &mut Vec<(Entity, Vec2)>,
+		(entity, translation): (Entity, Vec2),
+		is_member: bool,
+	) {
+		match ordered.iter().position(|(member, _)| member == &entity) {
+			Some(index) if is_member => ordered[index] = (entity, translation),
+			Some(index) => {
+				ordered.remove(index);
+			}
+			None if is_member => ordered.push((entity, translation)),
+			None => {}
+		}
+	}
+
+	/// Waypoint tiles in placement order
+	pub fn waypoints(&self) -> impl Iterator<Item = Entity> + '_ {
+		self.waypoints.iter().map(|(entity, _)| *entity)
+	}
+
+	/// Start, waypoints in placement order and end
+	pub fn route(&self) -> Option<Vec<Vec2>> {
+		let (_, start) = self.start?;
+		let (_, end) = self.end?;
+		let waypoints = self.waypoints.iter().map(|(_, translation)| *translation);
+
+		Some([start].into_iter().chain(waypoints).chain([end]).collect())
+	}
+
+	/// Agent starts paired with agent goals in placement order
+	pub fn agents(&self) -> Vec<(Vec2, Vec2)> {
+		self.agent_starts
+			.iter()
+			.zip(&self.agent_goals)
+			.map(|((_, start), (_, goal))| (*start, *goal))
+			.collect()
 	}
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "app"))]
 mod test_instantiation {
 	use super::*;
 	use crate::{
 		components::grid_context::GridContext,
 		test_tools::SingleThreaded,
-		traits::computable_grid::{ComputeGrid, ComputeGridNode},
+		traits::computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
 	};
-	use std::collections::HashSet;
 
 	#[derive(Asset, TypePath, Debug, PartialEq)]
 	struct _Grid;
@@ -205,15 +724,20 @@ mod test_instantiation {
 	#[derive(Debug, PartialEq)]
 	struct _Method {
 		grid: ComputeGrid,
-		obstacles: HashSet<ComputeGridNode>,
+		obstacles: Obstacles,
 	}
 
 	impl NewComputer for _Method {
-		fn new(grid: ComputeGrid, obstacles: HashSet<ComputeGridNode>) -> Self {
+		fn new(grid: ComputeGrid, obstacles: Obstacles) -> Self {
 			Self { grid, obstacles }
 		}
 	}
 
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(1, 2),
+		max: ComputeGridNode::new(3, 4),
+	};
+
 	fn setup() -> App {
 		let mut app = App::new().single_threaded(Update);
 		app.add_systems(Update, ComputePathMethod::<_Grid, _Method>::instantiate);
@@ -227,11 +751,8 @@ mod test_instantiation {
 		let entity = app
 			.world_mut()
 			.spawn(GridContext::<_Grid> {
-				grid: ComputeGrid {
-					min: ComputeGridNode::new(1, 2),
-					max: ComputeGridNode::new(3, 4),
-				},
-				obstacles: HashSet::from([ComputeGridNode::new(3, 4)]),
+				grid: GRID,
+				obstacles: Obstacles::from_nodes(GRID, [ComputeGridNode::new(3, 4)]),
 				..default()
 			})
 			.id();
@@ -240,11 +761,8 @@ mod test_instantiation {
 
 		assert_eq!(
 			Some(&ComputePathMethod::<_Grid, _Method>::new(_Method {
-				grid: ComputeGrid {
-					min: ComputeGridNode::new(1, 2),
-					max: ComputeGridNode::new(3, 4),
-				},
-				obstacles: HashSet::from([ComputeGridNode::new(3, 4)]),
+				grid: GRID,
+				obstacles: Obstacles::from_nodes(GRID, [ComputeGridNode::new(3, 4)]),
 			})),
 			app.world()
 				.entity(entity)
@@ -258,11 +776,8 @@ mod test_instantiation {
 		let entity = app
 			.world_mut()
 			.spawn(GridContext::<_Grid> {
-				grid: ComputeGrid {
-					min: ComputeGridNode::new(1, 2),
-					max: ComputeGridNode::new(3, 4),
-				},
-				obstacles: HashSet::from([ComputeGridNode::new(3, 4)]),
+				grid: GRID,
+				obstacles: Obstacles::from_nodes(GRID, [ComputeGridNode::new(3, 4)]),
 				..default()
 			})
 			.id();
@@ -287,11 +802,8 @@ mod test_instantiation {
 		let entity = app
 			.world_mut()
 			.spawn(GridContext::<_Grid> {
-				grid: ComputeGrid {
-					min: ComputeGridNode::new(1, 2),
-					max: ComputeGridNode::new(3, 4),
-				},
-				obstacles: HashSet::from([ComputeGridNode::new(3, 4)]),
+				grid: GRID,
+				obstacles: Obstacles::from_nodes(GRID, [ComputeGridNode::new(3, 4)]),
 				..default()
 			})
 			.id();
@@ -308,11 +820,8 @@ mod test_instantiation {
 
 		assert_eq!(
 			Some(&ComputePathMethod::<_Grid, _Method>::new(_Method {
-				grid: ComputeGrid {
-					min: ComputeGridNode::new(1, 2),
-					max: ComputeGridNode::new(3, 4),
-				},
-				obstacles: HashSet::from([ComputeGridNode::new(3, 4)]),
+				grid: GRID,
+				obstacles: Obstacles::from_nodes(GRID, [ComputeGridNode::new(3, 4)]),
 			})),
 			app.world()
 				.entity(entity)
@@ -321,13 +830,14 @@ mod test_instantiation {
 	}
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "app"))]
 mod test_compute_path {
 	use super::*;
 	use crate::{
 		assert_count,
 		components::{
 			computed_path::ComputedPath,
+			despawn::Despawn,
 			tile_type::{TileType, TileTypeValue},
 		},
 		new_handle,
@@ -335,7 +845,9 @@ mod test_compute_path {
 		test_tools::SingleThreaded,
 		traits::computable_grid::{ComputeGridNode, GetComputeGridNode, GetTranslation},
 	};
+	use bevy::tasks::TaskPool;
 	use mockall::{mock, predicate::eq};
+	use std::time::Duration;
 
 	#[derive(Asset, TypePath)]
 	struct _Grid;
@@ -365,15 +877,36 @@ mod test_compute_path {
 		let mut grids = Assets::default();
 
 		grids.insert(handle, _Grid);
+		AsyncComputeTaskPool::get_or_init(TaskPool::default);
 		app.insert_resource(grids);
+		app.add_event::<PathRequest>();
+		app.add_event::<PathResult>();
 		app.add_systems(
 			Update,
-			ComputePathMethod::<_Grid, Mock_Method>::compute_path,
+			(
+				PathRequest::from_tiles::<_Grid>,
+				ComputePathMethod::<_Grid, Mock_Method>::compute_path,
+				ComputePathMethod::<_Grid, Mock_Method>::poll_path,
+			)
+				.chain(),
 		);
 
 		app
 	}
 
+	/// Updates until no path computation is pending anymore.
+	fn update(app: &mut App) {
+		app.update();
+
+		while app
+			.world()
+			.iter_entities()
+			.any(|entity| is::<PendingPath<Mock_Method>>(&entity))
+		{
+			app.update();
+		}
+	}
+
 	fn child_of(entity: Entity) -> impl Fn(&EntityRef) -> bool {
 		move |child| {
 			child
@@ -412,7 +945,7 @@ mod test_compute_path {
 				Transform::default(),
 			));
 
-		app.update();
+		update(&mut app);
 
 		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
 		assert_eq!(
@@ -446,7 +979,7 @@ mod test_compute_path {
 				Transform::default(),
 			));
 
-		app.update();
+		update(&mut app);
 
 		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
 		assert_eq!(
@@ -458,6 +991,47 @@ mod test_compute_path {
 		);
 	}
 
+	#[test]
+	fn spawn_path_stats() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path().return_const(vec![
+						ComputeGridNode::new(1, 2),
+						ComputeGridNode::new(4, 2),
+						ComputeGridNode::new(4, 6),
+					]);
+				})),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::default(),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::default(),
+			));
+
+		update(&mut app);
+
+		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
+		let stats = path.get::<PathStats>().map(|stats| {
+			(
+				stats.method.as_str(),
+				stats.length,
+				stats.steps,
+				stats.nodes,
+				stats.turns,
+				stats.expanded,
+			)
+		});
+		assert_eq!(Some(("Mock_Method", 7., 7, 3, 1, None)), stats);
+	}
+
 	#[test]
 	fn spawn_path_nodes_as_children() {
 		let handle = new_handle!(_Grid);
@@ -482,7 +1056,7 @@ mod test_compute_path {
 			))
 			.id();
 
-		app.update();
+		update(&mut app);
 
 		let paths = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
 		assert_count!(1, paths.into_iter().filter(child_of(entity)));
@@ -510,7 +1084,7 @@ mod test_compute_path {
 				Transform::default(),
 			));
 
-		app.update();
+		update(&mut app);
 
 		assert_count!(0, app.world().iter_entities().filter(is::<ComputedPath>));
 	}
@@ -543,7 +1117,7 @@ mod test_compute_path {
 				Transform::from_xyz(4., 5., 6.),
 			));
 
-		app.update();
+		update(&mut app);
 	}
 
 	#[test]
@@ -574,7 +1148,7 @@ mod test_compute_path {
 				Transform::from_xyz(1., 2., 3.),
 			));
 
-		app.update();
+		update(&mut app);
 	}
 
 	#[test]
@@ -599,8 +1173,8 @@ mod test_compute_path {
 				Transform::from_xyz(4., 5., 6.),
 			));
 
-		app.update();
-		app.update();
+		update(&mut app);
+		update(&mut app);
 	}
 
 	#[test]
@@ -632,12 +1206,12 @@ mod test_compute_path {
 				Transform::from_xyz(4., 5., 6.),
 			));
 
-		app.update();
+		update(&mut app);
 		app.world_mut().spawn((
 			TileType::from_value(TileTypeValue::Start),
 			Transform::from_xyz(7., 8., 9.),
 		));
-		app.update();
+		update(&mut app);
 	}
 
 	#[test]
@@ -680,11 +1254,11 @@ mod test_compute_path {
 			.set_parent(entity)
 			.id();
 
-		app.update();
+		update(&mut app);
 		let mut child = app.world_mut().entity_mut(child);
 		let mut tile_type = child.get_mut::<TileType>().unwrap();
 		*tile_type = TileType::from_value(TileTypeValue::Start);
-		app.update();
+		update(&mut app);
 	}
 
 	#[test]
@@ -708,14 +1282,14 @@ mod test_compute_path {
 				Transform::from_xyz(4., 5., 6.),
 			));
 
-		app.update();
+		update(&mut app);
 		let [old_path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
 		let old_path = old_path.id();
 		app.world_mut().spawn((
 			TileType::from_value(TileTypeValue::Start),
 			Transform::from_xyz(7., 8., 9.),
 		));
-		app.update();
+		update(&mut app);
 
 		assert_eq!(
 			Some(&Despawn::NextFrame),
@@ -745,7 +1319,7 @@ mod test_compute_path {
 				Transform::from_xyz(4., 5., 6.),
 			));
 
-		app.update();
+		update(&mut app);
 
 		assert!(app.world().get_entity(other).is_ok());
 	}
@@ -777,11 +1351,11 @@ mod test_compute_path {
 			.set_parent(entity)
 			.id();
 
-		app.update();
+		update(&mut app);
 		app.world_mut()
 			.entity_mut(start)
 			.insert(TileType::from_value(TileTypeValue::Walkable));
-		app.update();
+		update(&mut app);
 
 		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
 		assert_eq!(Some(&Despawn::NextFrame), path.get::<Despawn>());
@@ -814,11 +1388,11 @@ mod test_compute_path {
 			.set_parent(entity)
 			.id();
 
-		app.update();
+		update(&mut app);
 		app.world_mut()
 			.entity_mut(end)
 			.insert(TileType::from_value(TileTypeValue::Walkable));
-		app.update();
+		update(&mut app);
 
 		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
 		assert_eq!(Some(&Despawn::NextFrame), path.get::<Despawn>());
@@ -851,9 +1425,9 @@ mod test_compute_path {
 			.set_parent(entity)
 			.id();
 
-		app.update();
+		update(&mut app);
 		app.world_mut().entity_mut(start).despawn();
-		app.update();
+		update(&mut app);
 
 		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
 		assert_eq!(Some(&Despawn::NextFrame), path.get::<Despawn>());
@@ -886,11 +1460,1271 @@ mod test_compute_path {
 			.set_parent(entity)
 			.id();
 
-		app.update();
+		update(&mut app);
 		app.world_mut().entity_mut(end).despawn();
-		app.update();
+		update(&mut app);
 
 		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
 		assert_eq!(Some(&Despawn::NextFrame), path.get::<Despawn>());
 	}
+
+	#[test]
+	fn call_path_for_each_leg_through_waypoint() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+
+		app.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path()
+						.times(1)
+						.with(
+							eq(ComputeGridNode::new(1, 2)),
+							eq(ComputeGridNode::new(7, 8)),
+						)
+						.return_const(vec![ComputeGridNode::new(1, 2)]);
+					mock.expect_path()
+						.times(1)
+						.with(
+							eq(ComputeGridNode::new(7, 8)),
+							eq(ComputeGridNode::new(4, 5)),
+						)
+						.return_const(vec![ComputeGridNode::new(4, 5)]);
+				})),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Waypoint),
+				Transform::from_xyz(7., 8., 9.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(4., 5., 6.),
+			));
+
+		update(&mut app);
+	}
+
+	#[test]
+	fn visit_waypoints_in_placement_order() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let entity = app
+			.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path()
+						.with(
+							eq(ComputeGridNode::new(1, 2)),
+							eq(ComputeGridNode::new(4, 5)),
+						)
+						.return_const(vec![ComputeGridNode::new(1, 2)]);
+					mock.expect_path()
+						.with(
+							eq(ComputeGridNode::new(1, 2)),
+							eq(ComputeGridNode::new(9, 9)),
+						)
+						.return_const(vec![ComputeGridNode::new(1, 2)]);
+					mock.expect_path()
+						.times(1)
+						.with(
+							eq(ComputeGridNode::new(9, 9)),
+							eq(ComputeGridNode::new(3, 3)),
+						)
+						.return_const(vec![ComputeGridNode::new(9, 9)]);
+					mock.expect_path()
+						.times(1)
+						.with(
+							eq(ComputeGridNode::new(3, 3)),
+							eq(ComputeGridNode::new(4, 5)),
+						)
+						.return_const(vec![ComputeGridNode::new(4, 5)]);
+					mock.expect_path().return_const(vec![]);
+				})),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(4., 5., 6.),
+			))
+			.id();
+
+		update(&mut app);
+		app.world_mut()
+			.spawn((
+				TileType::from_value(TileTypeValue::Waypoint),
+				Transform::from_xyz(9., 9., 0.),
+			))
+			.set_parent(entity);
+		update(&mut app);
+		app.world_mut()
+			.spawn((
+				TileType::from_value(TileTypeValue::Waypoint),
+				Transform::from_xyz(3., 3., 0.),
+			))
+			.set_parent(entity);
+		update(&mut app);
+	}
+
+	#[test]
+	fn join_legs_through_waypoint() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path()
+						.with(
+							eq(ComputeGridNode::new(1, 2)),
+							eq(ComputeGridNode::new(7, 8)),
+						)
+						.return_const(vec![ComputeGridNode::new(1, 2), ComputeGridNode::new(7, 8)]);
+					mock.expect_path()
+						.with(
+							eq(ComputeGridNode::new(7, 8)),
+							eq(ComputeGridNode::new(4, 5)),
+						)
+						.return_const(vec![ComputeGridNode::new(7, 8), ComputeGridNode::new(4, 5)]);
+				})),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Waypoint),
+				Transform::from_xyz(7., 8., 9.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(4., 5., 6.),
+			));
+
+		update(&mut app);
+
+		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
+		assert_eq!(
+			Some(&ComputedPath {
+				path: vec![
+					Vec3::new(1., 2., 1.),
+					Vec3::new(7., 8., 1.),
+					Vec3::new(4., 5., 1.)
+				],
+				draw_connections: false,
+			}),
+			path.get::<ComputedPath>()
+		);
+	}
+
+	#[test]
+	fn empty_path_when_leg_through_waypoint_unreachable() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path()
+						.with(
+							eq(ComputeGridNode::new(1, 2)),
+							eq(ComputeGridNode::new(7, 8)),
+						)
+						.return_const(vec![]);
+					mock.expect_path()
+						.with(
+							eq(ComputeGridNode::new(7, 8)),
+							eq(ComputeGridNode::new(4, 5)),
+						)
+						.return_const(vec![ComputeGridNode::new(7, 8), ComputeGridNode::new(4, 5)]);
+				})),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Waypoint),
+				Transform::from_xyz(7., 8., 9.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(4., 5., 6.),
+			));
+
+		update(&mut app);
+
+		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
+		assert_eq!(
+			Some(&ComputedPath {
+				path: vec![],
+				draw_connections: false,
+			}),
+			path.get::<ComputedPath>()
+		);
+	}
+
+	#[test]
+	fn skip_waypoint_after_it_was_removed() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let entity = app
+			.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path()
+						.times(1)
+						.with(
+							eq(ComputeGridNode::new(1, 2)),
+							eq(ComputeGridNode::new(4, 5)),
+						)
+						.return_const(vec![]);
+					mock.expect_path()
+						.return_const(vec![ComputeGridNode::new(7, 8)]);
+				})),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(4., 5., 6.),
+			))
+			.id();
+		let waypoint = app
+			.world_mut()
+			.spawn((
+				TileType::from_value(TileTypeValue::Waypoint),
+				Transform::from_xyz(7., 8., 9.),
+			))
+			.set_parent(entity)
+			.id();
+
+		update(&mut app);
+		app.world_mut()
+			.entity_mut(waypoint)
+			.insert(TileType::from_value(TileTypeValue::Walkable));
+		update(&mut app);
+	}
+
+	#[test]
+	fn compute_path_in_pending_task() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path().returning(|start, _| {
+						std::thread::sleep(Duration::from_millis(50));
+						vec![start]
+					});
+				})),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(4., 5., 6.),
+			));
+
+		app.update();
+
+		assert_eq!(
+			(1, 0),
+			(
+				app.world()
+					.iter_entities()
+					.filter(is::<PendingPath<Mock_Method>>)
+					.count(),
+				app.world()
+					.iter_entities()
+					.filter(is::<ComputedPath>)
+					.count(),
+			)
+		);
+	}
+
+	#[test]
+	fn cancel_pending_path_when_start_changed() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path()
+						.with(
+							eq(ComputeGridNode::new(1, 2)),
+							eq(ComputeGridNode::new(4, 5)),
+						)
+						.returning(|start, _| {
+							std::thread::sleep(Duration::from_millis(50));
+							vec![start]
+						});
+					mock.expect_path()
+						.with(
+							eq(ComputeGridNode::new(7, 8)),
+							eq(ComputeGridNode::new(4, 5)),
+						)
+						.return_const(vec![ComputeGridNode::new(7, 8)]);
+				})),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(4., 5., 6.),
+			));
+
+		app.update();
+		app.world_mut().spawn((
+			TileType::from_value(TileTypeValue::Start),
+			Transform::from_xyz(7., 8., 9.),
+		));
+		update(&mut app);
+
+		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
+		assert_eq!(
+			Some(&ComputedPath {
+				path: vec![Vec3::new(7., 8., 1.)],
+				draw_connections: false,
+			}),
+			path.get::<ComputedPath>()
+		);
+	}
+
+	#[test]
+	fn cancel_pending_path_when_start_removed() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let entity = app
+			.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path().returning(|start, _| {
+						std::thread::sleep(Duration::from_millis(50));
+						vec![start]
+					});
+				})),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(4., 5., 6.),
+			))
+			.id();
+		let start = app
+			.world_mut()
+			.spawn((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.set_parent(entity)
+			.id();
+
+		app.update();
+		app.world_mut().entity_mut(start).despawn();
+		app.update();
+
+		assert_eq!(
+			(0, 0),
+			(
+				app.world()
+					.iter_entities()
+					.filter(is::<PendingPath<Mock_Method>>)
+					.count(),
+				app.world()
+					.iter_entities()
+					.filter(is::<ComputedPath>)
+					.count(),
+			)
+		);
+	}
+}
+
+#[cfg(all(test, feature = "app"))]
+mod test_compute_path_sliced {
+	use super::*;
+	use crate::{
+		assert_count,
+		components::{computed_path::ComputedPath, despawn::Despawn},
+		new_handle,
+		new_mock,
+		test_tools::SingleThreaded,
+	};
+	use mockall::{
+		mock,
+		predicate::{always, eq},
+	};
+	use std::sync::Mutex;
+
+	#[derive(Asset, TypePath)]
+	struct _Grid;
+
+	impl GetComputeGridNode for _Grid {
+		fn compute_grid_node(&self, Vec2 { x, y }: Vec2) -> Option<ComputeGridNode> {
+			Some(ComputeGridNode::new(x as i32, y as i32))
+		}
+	}
+
+	impl GetTranslation for _Grid {
+		fn translation(&self, ComputeGridNode { x, y }: ComputeGridNode) -> Option<Vec2> {
+			Some(Vec2::new(x as f32, y as f32))
+		}
+	}
+
+	mock! {
+		_Method {}
+		impl ComputePath for _Method {
+			fn draw_connections(&self) -> bool;
+			fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode>;
+		}
+		impl ComputePathSliced for _Method {
+			type TSearch = usize;
+			fn start_search(&self, start: ComputeGridNode, end: ComputeGridNode) -> usize;
+			fn continue_search(&self, search: &mut usize, budget: usize) -> SearchProgress;
+		}
+	}
+
+	fn setup(handle: &Handle<_Grid>, budget: ExpansionBudget) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut grids = Assets::default();
+
+		grids.insert(handle, _Grid);
+		app.insert_resource(grids);
+		app.insert_resource(budget);
+		app.add_event::<PathRequest>();
+		app.add_event::<PathResult>();
+		app.add_systems(
+			Update,
+			(
+				PathRequest::from_tiles::<_Grid>,
+				ComputePathMethod::<_Grid, Mock_Method>::compute_path_sliced,
+				ComputePathMethod::<_Grid, Mock_Method>::continue_sliced_path,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn is<TComponent>(entity: &EntityRef) -> bool
+	where
+		TComponent: Component,
+	{
+		entity.contains::<TComponent>()
+	}
+
+	fn spawn_grid(app: &mut App, handle: Handle<_Grid>, method: Mock_Method) -> Entity {
+		app.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(method),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(4., 5., 6.),
+			))
+			.id()
+	}
+
+	#[test]
+	fn spawn_path_when_search_done() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle, ExpansionBudget::default());
+		spawn_grid(
+			&mut app,
+			handle,
+			new_mock!(Mock_Method, |mock| {
+				mock.expect_draw_connections().return_const(true);
+				mock.expect_start_search().return_const(0_usize);
+				mock.expect_continue_search()
+					.return_const(SearchProgress::Done {
+						expanded: 1,
+						path: vec![ComputeGridNode::new(1, 2), ComputeGridNode::new(4, 5)],
+					});
+			}),
+		);
+
+		app.update();
+
+		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
+		assert_eq!(
+			Some(&ComputedPath {
+				path: vec![Vec3::new(1., 2., 1.), Vec3::new(4., 5., 1.)],
+				draw_connections: true,
+			}),
+			path.get::<ComputedPath>()
+		);
+	}
+
+	#[test]
+	fn spawn_path_stats_with_expansions_of_all_frames() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(
+			&handle,
+			ExpansionBudget {
+				per_frame: 5,
+				per_request: 5,
+				..default()
+			},
+		);
+		spawn_grid(
+			&mut app,
+			handle,
+			new_mock!(Mock_Method, |mock| {
+				let mut calls = 0;
+				mock.expect_draw_connections().return_const(true);
+				mock.expect_start_search().return_const(0_usize);
+				mock.expect_continue_search().returning(move |_, budget| {
+					calls += 1;
+					match calls {
+						1 => SearchProgress::Pending { expanded: budget },
+						_ => SearchProgress::Done {
+							expanded: 2,
+							path: vec![ComputeGridNode::new(1, 2)],
+						},
+					}
+				});
+			}),
+		);
+
+		app.update();
+		app.update();
+
+		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
+		assert_eq!(
+			Some(Some(7)),
+			path.get::<PathStats>().map(|stats| stats.expanded)
+		);
+	}
+
+	#[test]
+	fn start_search_with_start_and_end() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle, ExpansionBudget::default());
+		spawn_grid(
+			&mut app,
+			handle,
+			new_mock!(Mock_Method, |mock| {
+				mock.expect_draw_connections().return_const(true);
+				mock.expect_start_search()
+					.times(1)
+					.with(
+						eq(ComputeGridNode::new(1, 2)),
+						eq(ComputeGridNode::new(4, 5)),
+					)
+					.return_const(0_usize);
+				mock.expect_continue_search()
+					.return_const(SearchProgress::Pending { expanded: 1 });
+			}),
+		);
+
+		app.update();
+		app.update();
+	}
+
+	#[test]
+	fn continue_search_over_multiple_frames() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle, ExpansionBudget::default());
+		spawn_grid(
+			&mut app,
+			handle,
+			new_mock!(Mock_Method, |mock| {
+				mock.expect_draw_connections().return_const(true);
+				mock.expect_start_search().return_const(0_usize);
+				mock.expect_continue_search()
+					.returning(|frames: &mut usize, _| {
+						*frames += 1;
+						match frames {
+							3 => SearchProgress::Done {
+								expanded: 1,
+								path: vec![ComputeGridNode::new(1, 2)],
+							},
+							_ => SearchProgress::Pending { expanded: 1 },
+						}
+					});
+			}),
+		);
+
+		app.update();
+		app.update();
+		let paths_before_done = app
+			.world()
+			.iter_entities()
+			.filter(is::<ComputedPath>)
+			.count();
+		app.update();
+		let paths_after_done = app
+			.world()
+			.iter_entities()
+			.filter(is::<ComputedPath>)
+			.count();
+
+		assert_eq!((0, 1), (paths_before_done, paths_after_done));
+	}
+
+	#[test]
+	fn limit_expansions_per_request() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(
+			&handle,
+			ExpansionBudget {
+				per_request: 5,
+				per_frame: 100,
+				..default()
+			},
+		);
+		spawn_grid(
+			&mut app,
+			handle,
+			new_mock!(Mock_Method, |mock| {
+				mock.expect_draw_connections().return_const(true);
+				mock.expect_start_search().return_const(0_usize);
+				mock.expect_continue_search()
+					.times(1)
+					.with(always(), eq(5))
+					.return_const(SearchProgress::Pending { expanded: 5 });
+			}),
+		);
+
+		app.update();
+	}
+
+	#[test]
+	fn share_expansions_per_frame_between_requests() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(
+			&handle,
+			ExpansionBudget {
+				per_request: 5,
+				per_frame: 7,
+				..default()
+			},
+		);
+		let budgets = Arc::new(Mutex::new(vec![]));
+		for _ in 0..2 {
+			let budgets = budgets.clone();
+			spawn_grid(
+				&mut app,
+				handle.clone(),
+				new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(true);
+					mock.expect_start_search().return_const(0_usize);
+					let budgets = budgets.clone();
+					mock.expect_continue_search().returning(move |_, budget| {
+						budgets.lock().unwrap().push(budget);
+						SearchProgress::Pending { expanded: budget }
+					});
+				}),
+			);
+		}
+
+		app.update();
+
+		let mut budgets = budgets.lock().unwrap().clone();
+		budgets.sort();
+		assert_eq!(vec![2, 5], budgets);
+	}
+
+	#[test]
+	fn serve_requests_in_turns_when_frame_budget_used_up() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(
+			&handle,
+			ExpansionBudget {
+				per_request: 5,
+				per_frame: 5,
+				..default()
+			},
+		);
+		let served = Arc::new(Mutex::new(vec![]));
+		for i in 0..2 {
+			let served = served.clone();
+			spawn_grid(
+				&mut app,
+				handle.clone(),
+				new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(true);
+					mock.expect_start_search().return_const(0_usize);
+					let served = served.clone();
+					mock.expect_continue_search().returning(move |_, budget| {
+						served.lock().unwrap().push(i);
+						SearchProgress::Pending { expanded: budget }
+					});
+				}),
+			);
+		}
+
+		app.update();
+		app.update();
+
+		let mut served = served.lock().unwrap().clone();
+		served.sort();
+		assert_eq!(vec![0, 1], served);
+	}
+
+	#[test]
+	fn join_legs_through_waypoint() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle, ExpansionBudget::default());
+		let entity = spawn_grid(
+			&mut app,
+			handle,
+			new_mock!(Mock_Method, |mock| {
+				mock.expect_draw_connections().return_const(true);
+				mock.expect_start_search()
+					.with(
+						eq(ComputeGridNode::new(1, 2)),
+						eq(ComputeGridNode::new(7, 8)),
+					)
+					.return_const(0_usize);
+				mock.expect_start_search()
+					.with(
+						eq(ComputeGridNode::new(7, 8)),
+						eq(ComputeGridNode::new(4, 5)),
+					)
+					.return_const(1_usize);
+				mock.expect_continue_search()
+					.returning(|leg: &mut usize, _| SearchProgress::Done {
+						expanded: 1,
+						path: match leg {
+							0 => vec![ComputeGridNode::new(1, 2), ComputeGridNode::new(7, 8)],
+							_ => vec![ComputeGridNode::new(7, 8), ComputeGridNode::new(4, 5)],
+						},
+					});
+			}),
+		);
+		app.world_mut()
+			.spawn((
+				TileType::from_value(TileTypeValue::Waypoint),
+				Transform::from_xyz(7., 8., 9.),
+			))
+			.set_parent(entity);
+
+		app.update();
+
+		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
+		assert_eq!(
+			Some(&ComputedPath {
+				path: vec![
+					Vec3::new(1., 2., 1.),
+					Vec3::new(7., 8., 1.),
+					Vec3::new(4., 5., 1.)
+				],
+				draw_connections: true,
+			}),
+			path.get::<ComputedPath>()
+		);
+	}
+
+	#[test]
+	fn stop_search_when_start_removed() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle, ExpansionBudget::default());
+		spawn_grid(
+			&mut app,
+			handle,
+			new_mock!(Mock_Method, |mock| {
+				mock.expect_draw_connections().return_const(true);
+				mock.expect_start_search().return_const(0_usize);
+				mock.expect_continue_search()
+					.times(1)
+					.return_const(SearchProgress::Pending { expanded: 1 });
+			}),
+		);
+
+		app.update();
+		let start = app
+			.world()
+			.iter_entities()
+			.find(|e| e.get::<TileType>() == Some(&TileType::from_value(TileTypeValue::Start)))
+			.map(|e| e.id())
+			.unwrap();
+		app.world_mut().entity_mut(start).despawn();
+		app.update();
+
+		assert_count!(
+			0,
+			app.world()
+				.iter_entities()
+				.filter(is::<SlicedPath<Mock_Method>>)
+		);
+	}
+
+	fn improve_then_finish(mock: &mut Mock_Method) {
+		let mut calls = 0;
+		mock.expect_draw_connections().return_const(true);
+		mock.expect_start_search().return_const(0_usize);
+		mock.expect_continue_search().returning(move |_, _| {
+			calls += 1;
+			match calls {
+				1 => SearchProgress::Improved {
+					expanded: 5,
+					path: vec![ComputeGridNode::new(1, 2), ComputeGridNode::new(4, 5)],
+					bound: 2.,
+				},
+				_ => SearchProgress::Done {
+					expanded: 5,
+					path: vec![ComputeGridNode::new(1, 2), ComputeGridNode::new(4, 5)],
+				},
+			}
+		});
+	}
+
+	#[test]
+	fn spawn_improved_path_with_bound_and_keep_searching() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(
+			&handle,
+			ExpansionBudget {
+				per_request: 5,
+				per_frame: 5,
+				..default()
+			},
+		);
+		spawn_grid(
+			&mut app,
+			handle,
+			new_mock!(Mock_Method, improve_then_finish),
+		);
+
+		app.update();
+
+		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
+		assert_eq!(
+			(Some(Some(2.)), 1),
+			(
+				path.get::<PathStats>().map(|stats| stats.bound),
+				app.world()
+					.iter_entities()
+					.filter(is::<SlicedPath<Mock_Method>>)
+					.count()
+			)
+		);
+	}
+
+	#[test]
+	fn replace_improved_path_with_optimal_path_when_done() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(
+			&handle,
+			ExpansionBudget {
+				per_request: 5,
+				per_frame: 5,
+				..default()
+			},
+		);
+		spawn_grid(
+			&mut app,
+			handle,
+			new_mock!(Mock_Method, improve_then_finish),
+		);
+
+		app.update();
+		app.update();
+
+		let [path] = assert_count!(
+			1,
+			app.world()
+				.iter_entities()
+				.filter(is::<ComputedPath>)
+				.filter(|entity| !entity.contains::<Despawn>())
+		);
+		assert_eq!(
+			(Some(Some(1.)), Some(Some(10)), 0),
+			(
+				path.get::<PathStats>().map(|stats| stats.bound),
+				path.get::<PathStats>().map(|stats| stats.expanded),
+				app.world()
+					.iter_entities()
+					.filter(is::<SlicedPath<Mock_Method>>)
+					.count()
+			)
+		);
+	}
+
+	#[test]
+	fn settle_with_improved_path_after_anytime_limit() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(
+			&handle,
+			ExpansionBudget {
+				per_request: 5,
+				per_frame: 5,
+				per_anytime_search: 5,
+			},
+		);
+		spawn_grid(
+			&mut app,
+			handle,
+			new_mock!(Mock_Method, improve_then_finish),
+		);
+
+		app.update();
+		app.update();
+
+		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
+		assert_eq!(
+			(Some(Some(2.)), 0),
+			(
+				path.get::<PathStats>().map(|stats| stats.bound),
+				app.world()
+					.iter_entities()
+					.filter(is::<SlicedPath<Mock_Method>>)
+					.count()
+			)
+		);
+	}
+}
+
+#[cfg(all(test, feature = "app"))]
+mod test_path_request {
+	use super::*;
+	use crate::{
+		assert_count,
+		components::computed_path::ComputedPath,
+		events::path_request::PathOptions,
+		new_handle,
+		new_mock,
+		test_tools::SingleThreaded,
+	};
+	use bevy::tasks::TaskPool;
+	use mockall::{mock, predicate::eq};
+	use std::time::Duration;
+
+	#[derive(Asset, TypePath)]
+	struct _Grid;
+
+	impl GetComputeGridNode for _Grid {
+		fn compute_grid_node(&self, Vec2 { x, y }: Vec2) -> Option<ComputeGridNode> {
+			Some(ComputeGridNode::new(x as i32, y as i32))
+		}
+	}
+
+	impl GetTranslation for _Grid {
+		fn translation(&self, ComputeGridNode { x, y }: ComputeGridNode) -> Option<Vec2> {
+			Some(Vec2::new(x as f32, y as f32))
+		}
+	}
+
+	mock! {
+		_Method {}
+		impl ComputePath for _Method {
+			fn draw_connections(&self) -> bool;
+			fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode>;
+		}
+	}
+
+	#[derive(Resource, Default)]
+	struct _Results(Vec<PathResult>);
+
+	impl _Results {
+		fn collect(mut results: EventReader<PathResult>, mut collected: ResMut<Self>) {
+			collected.0.extend(results.read().cloned());
+		}
+	}
+
+	fn setup(handle: &Handle<_Grid>) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut grids = Assets::default();
+
+		grids.insert(handle, _Grid);
+		AsyncComputeTaskPool::get_or_init(TaskPool::default);
+		app.insert_resource(grids);
+		app.init_resource::<_Results>();
+		app.add_event::<PathRequest>();
+		app.add_event::<PathResult>();
+		app.add_systems(
+			Update,
+			(
+				ComputePathMethod::<_Grid, Mock_Method>::compute_path,
+				ComputePathMethod::<_Grid, Mock_Method>::poll_path,
+				_Results::collect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	/// Updates until no path computation is pending anymore.
+	fn update(app: &mut App) {
+		app.update();
+
+		while app
+			.world()
+			.iter_entities()
+			.any(|entity| is::<PendingPath<Mock_Method>>(&entity))
+		{
+			app.update();
+		}
+	}
+
+	fn is<TComponent>(entity: &EntityRef) -> bool
+	where
+		TComponent: Component,
+	{
+		entity.contains::<TComponent>()
+	}
+
+	fn request(requester: Entity, grid: Entity, start: Vec2, draw: bool) -> PathRequest {
+		PathRequest {
+			requester,
+			grid,
+			start,
+			end: Vec2::new(4., 5.),
+			options: PathOptions { draw, ..default() },
+		}
+	}
+
+	#[test]
+	fn send_result() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let grid = app
+			.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path()
+						.with(
+							eq(ComputeGridNode::new(1, 2)),
+							eq(ComputeGridNode::new(4, 5)),
+						)
+						.return_const(vec![ComputeGridNode::new(1, 2), ComputeGridNode::new(4, 5)]);
+				})),
+			))
+			.id();
+		let requester = app.world_mut().spawn_empty().id();
+		let request = request(requester, grid, Vec2::new(1., 2.), false);
+
+		app.world_mut().send_event(request.clone());
+		update(&mut app);
+
+		assert_eq!(
+			(
+				vec![PathResult {
+					request,
+					path: vec![Vec2::new(1., 2.), Vec2::new(4., 5.)],
+				}],
+				0
+			),
+			(
+				app.world().resource::<_Results>().0.clone(),
+				app.world()
+					.iter_entities()
+					.filter(is::<ComputedPath>)
+					.count()
+			)
+		);
+	}
+
+	#[test]
+	fn draw_result_when_requested() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let grid = app
+			.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(true);
+					mock.expect_path()
+						.return_const(vec![ComputeGridNode::new(1, 2), ComputeGridNode::new(4, 5)]);
+				})),
+			))
+			.id();
+
+		app.world_mut()
+			.send_event(request(grid, grid, Vec2::new(1., 2.), true));
+		update(&mut app);
+
+		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
+		assert_eq!(
+			(
+				Some(&ComputedPath {
+					path: vec![Vec3::new(1., 2., 1.), Vec3::new(4., 5., 1.)],
+					draw_connections: true,
+				}),
+				Some(grid)
+			),
+			(
+				path.get::<ComputedPath>(),
+				path.get::<Parent>().map(Parent::get)
+			)
+		);
+	}
+
+	#[test]
+	fn cancel_earlier_request_of_same_requester() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let grid = app
+			.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path().returning(|start, _| {
+						std::thread::sleep(Duration::from_millis(50));
+						vec![start]
+					});
+				})),
+			))
+			.id();
+		let requester = app.world_mut().spawn_empty().id();
+
+		app.world_mut()
+			.send_event(request(requester, grid, Vec2::new(1., 2.), false));
+		app.update();
+		app.world_mut()
+			.send_event(request(requester, grid, Vec2::new(7., 8.), false));
+		update(&mut app);
+
+		assert_eq!(
+			vec![vec![Vec2::new(7., 8.)]],
+			app.world()
+				.resource::<_Results>()
+				.0
+				.iter()
+				.map(|result| result.path.clone())
+				.collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn keep_requests_of_different_requesters() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let grid = app
+			.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path().returning(|start, _| vec![start]);
+				})),
+			))
+			.id();
+		let a = app.world_mut().spawn_empty().id();
+		let b = app.world_mut().spawn_empty().id();
+
+		app.world_mut()
+			.send_event(request(a, grid, Vec2::new(1., 2.), false));
+		app.world_mut()
+			.send_event(request(b, grid, Vec2::new(7., 8.), false));
+		update(&mut app);
+
+		let mut results = app
+			.world()
+			.resource::<_Results>()
+			.0
+			.iter()
+			.map(|result| (result.request.requester, result.path.clone()))
+			.collect::<Vec<_>>();
+		results.sort_by_key(|(requester, _)| *requester);
+		assert_eq!(
+			vec![(a, vec![Vec2::new(1., 2.)]), (b, vec![Vec2::new(7., 8.)])],
+			results
+		);
+	}
+
+	#[test]
+	fn ignore_request_for_grid_without_method() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let grid = app.world_mut().spawn(GridContext::from_handle(handle)).id();
+
+		app.world_mut()
+			.send_event(request(grid, grid, Vec2::new(1., 2.), true));
+		update(&mut app);
+
+		assert_eq!(
+			(0, 0),
+			(
+				app.world().resource::<_Results>().0.len(),
+				app.world()
+					.iter_entities()
+					.filter(is::<PendingRequest>)
+					.count()
+			)
+		);
+	}
+
+	#[test]
+	fn answer_request_for_own_method() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let grid = app
+			.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path()
+						.return_const(vec![ComputeGridNode::new(1, 2)]);
+				})),
+			))
+			.id();
+		let mut request = request(grid, grid, Vec2::new(1., 2.), false);
+		request.options = request.options.with_method::<Mock_Method>();
+
+		app.world_mut().send_event(request);
+		update(&mut app);
+
+		assert_eq!(1, app.world().resource::<_Results>().0.len());
+	}
+
+	#[test]
+	fn ignore_request_for_other_method() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let grid = app
+			.world_mut()
+			.spawn((
+				GridContext::from_handle(handle),
+				ComputePathMethod::<_Grid, Mock_Method>::new(new_mock!(Mock_Method, |mock| {
+					mock.expect_draw_connections().return_const(false);
+					mock.expect_path().never();
+				})),
+			))
+			.id();
+		let mut request = request(grid, grid, Vec2::new(1., 2.), false);
+		request.options = request.options.with_method::<u32>();
+
+		app.world_mut().send_event(request);
+		update(&mut app);
+
+		assert_eq!(0, app.world().resource::<_Results>().0.len());
+	}
 }
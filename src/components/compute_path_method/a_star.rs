@@ -1,20 +1,46 @@
 use crate::traits::{
-	computable_grid::{ComputeGrid, ComputeGridNode},
-	compute_path::{ComputePath, NewComputer},
+	computable_grid::{
+		dense::{NodeMap, Obstacles},
+		ComputeGrid,
+		ComputeGridNode,
+	},
+	compute_path::{ComputePath, ComputePathSliced, NewComputer, SearchProgress},
+	cost::Cost,
+	heuristic::{Heuristic, Manhattan, NewHeuristic},
+	space_time_constraints::SpaceTimeConstraints,
 };
 use std::{
 	cmp::{Ordering, Reverse},
 	collections::{BinaryHeap, HashMap, HashSet},
+	marker::PhantomData,
 };
 
-pub struct AStar {
+/// A* with horizontal and vertical steps of cost 1.
+pub struct AStar<THeuristic = Manhattan, TCost = f32> {
 	grid: ComputeGrid,
-	obstacles: HashSet<ComputeGridNode>,
+	obstacles: Obstacles,
+	heuristic: THeuristic,
+	tie_break: TieBreak,
+	_p: PhantomData<TCost>,
 }
 
-impl AStar {
+impl<THeuristic, TCost> AStar<THeuristic, TCost> {
 	const NEIGHBORS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
 
+	pub fn with_tie_break(self, tie_break: TieBreak) -> Self {
+		Self { tie_break, ..self }
+	}
+
+	pub fn with_heuristic<TOther>(self, heuristic: TOther) -> AStar<TOther, TCost> {
+		AStar {
+			grid: self.grid,
+			obstacles: self.obstacles,
+			heuristic,
+			tie_break: self.tie_break,
+			_p: PhantomData,
+		}
+	}
+
 	fn neighbors<'a>(
 		&'a self,
 		center: &'a ComputeGridNode,
@@ -30,69 +56,199 @@ impl AStar {
 			})
 	}
 
-	fn distance(a: ComputeGridNode, b: ComputeGridNode) -> f32 {
-		(a.x.abs_diff(b.x) + a.y.abs_diff(b.y)) as f32
+	/// One node per time step. `end` is only accepted, when staying there is allowed by
+	/// all later constraints.
+	pub fn path_in_time<TConstraints>(
+		&self,
+		start: ComputeGridNode,
+		end: ComputeGridNode,
+		constraints: &TConstraints,
+	) -> Vec<ComputeGridNode>
+	where
+		TConstraints: SpaceTimeConstraints,
+	{
+		let last_constrained = constraints.last_constrained();
+		let can_stay = |t: u32| (t..last_constrained).all(|t| constraints.allows(end, end, t));
+		// beyond the last constraint, time steps of the same node are equivalent
+		let key = |node: ComputeGridNode, t: u32| (node, t.min(last_constrained + 1));
+		let h = |node: ComputeGridNode| node.x.abs_diff(end.x) + node.y.abs_diff(end.y);
+		let mut open = BinaryHeap::from([Reverse((h(start), 0, start))]);
+		let mut parents = HashMap::new();
+		let mut closed = HashSet::new();
+
+		while let Some(Reverse((_, t, current))) = open.pop() {
+			if current == end && can_stay(t) {
+				return Self::construct_path_in_time(&parents, (current, t));
+			}
+
+			if !closed.insert(key(current, t)) {
+				continue;
+			}
+
+			let waiting = (t < last_constrained).then_some(current);
+
+			for neighbor in self.neighbors(&current).chain(waiting) {
+				if self.obstacles.contains(&neighbor) {
+					continue;
+				}
+				if !constraints.allows(current, neighbor, t) {
+					continue;
+				}
+				if closed.contains(&key(neighbor, t + 1)) {
+					continue;
+				}
+				if parents.contains_key(&(neighbor, t + 1)) {
+					continue;
+				}
+
+				parents.insert((neighbor, t + 1), (current, t));
+				open.push(Reverse((t + 1 + h(neighbor), t + 1, neighbor)));
+			}
+		}
+
+		vec![]
+	}
+
+	fn construct_path_in_time(
+		parents: &HashMap<(ComputeGridNode, u32), (ComputeGridNode, u32)>,
+		mut state: (ComputeGridNode, u32),
+	) -> Vec<ComputeGridNode> {
+		let mut path = vec![state.0];
+
+		while let Some(parent) = parents.get(&state) {
+			path.push(parent.0);
+			state = *parent;
+		}
+
+		path.reverse();
+		path
 	}
 }
 
-impl NewComputer for AStar {
-	fn new(grid: ComputeGrid, obstacles: HashSet<ComputeGridNode>) -> Self {
-		Self { grid, obstacles }
+impl<THeuristic, TCost> NewComputer for AStar<THeuristic, TCost>
+where
+	THeuristic: NewHeuristic,
+{
+	fn new(grid: ComputeGrid, obstacles: Obstacles) -> Self {
+		Self {
+			heuristic: THeuristic::new(grid, &obstacles),
+			grid,
+			obstacles,
+			tie_break: TieBreak::default(),
+			_p: PhantomData,
+		}
 	}
 }
 
-impl ComputePath for AStar {
+impl<THeuristic, TCost> ComputePath for AStar<THeuristic, TCost>
+where
+	THeuristic: Heuristic<TCost> + Clone + Send + Sync + 'static,
+	TCost: Cost,
+{
 	fn draw_connections(&self) -> bool {
 		const { true }
 	}
 
 	fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode> {
-		let mut open = OpenList::new(start, end, &Self::distance);
-		let mut closed = ClosedList::new(start);
-		let mut g_scores = GScores::new(start);
+		let (_, path) = self.path_with_expansions(start, end);
+		path
+	}
+
+	fn path_with_expansions(
+		&self,
+		start: ComputeGridNode,
+		end: ComputeGridNode,
+	) -> (Option<usize>, Vec<ComputeGridNode>) {
+		let mut search = self.start_search(start, end);
+
+		match self.continue_search(&mut search, usize::MAX) {
+			SearchProgress::Done { expanded, path }
+			| SearchProgress::Improved { expanded, path, .. } => (Some(expanded), path),
+			SearchProgress::Pending { expanded } => (Some(expanded), vec![]),
+		}
+	}
+}
+
+impl<THeuristic, TCost> ComputePathSliced for AStar<THeuristic, TCost>
+where
+	THeuristic: Heuristic<TCost> + Clone + Send + Sync + 'static,
+	TCost: Cost,
+{
+	type TSearch = AStarSearch<THeuristic, TCost>;
 
-		while let Some(current) = open.pop_lowest_f() {
-			if current == end {
-				return closed.construct_path_from(current).collect();
+	fn start_search(&self, start: ComputeGridNode, end: ComputeGridNode) -> Self::TSearch {
+		AStarSearch {
+			end,
+			open: OpenList::new(start, end, self.heuristic.clone()).with_tie_break(self.tie_break),
+			closed: ClosedList::new(self.grid, start),
+			g_scores: GScores::new(self.grid, start),
+		}
+	}
+
+	fn continue_search(&self, search: &mut Self::TSearch, budget: usize) -> SearchProgress {
+		let mut expanded = 0;
+
+		while expanded < budget {
+			let Some(current) = search.open.pop_lowest_f() else {
+				return SearchProgress::Done {
+					expanded,
+					path: vec![],
+				};
+			};
+
+			if current == search.end {
+				let closed = std::mem::take(&mut search.closed);
+				let mut path = closed.construct_path_from(current).collect::<Vec<_>>();
+				path.reverse();
+				return SearchProgress::Done { expanded, path };
 			}
 
+			expanded += 1;
+
 			for neighbor in self.neighbors(&current) {
 				if self.obstacles.contains(&neighbor) {
 					continue;
 				}
 
-				let g = g_scores.get(&current) + Self::distance(current, neighbor);
+				let g = search.g_scores.get(&current) + Manhattan::distance(current, neighbor);
 
-				if g >= g_scores.get(&neighbor) {
+				if g >= search.g_scores.get(&neighbor) {
 					continue;
 				}
 
-				open.push(neighbor, g);
-				closed.insert(neighbor, current);
-				g_scores.insert(neighbor, g);
+				search.open.push(neighbor, g);
+				search.closed.insert(neighbor, current);
+				search.g_scores.insert(neighbor, g);
 			}
 		}
 
-		vec![]
+		SearchProgress::Pending { expanded }
 	}
 }
 
+pub struct AStarSearch<THeuristic = Manhattan, TCost = f32> {
+	end: ComputeGridNode,
+	open: OpenList<THeuristic, TCost>,
+	closed: ClosedList,
+	g_scores: GScores<TCost>,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct ClosedList {
 	start: ComputeGridNode,
-	parents: HashMap<ComputeGridNode, ComputeGridNode>,
+	parents: NodeMap<Option<ComputeGridNode>>,
 }
 
 impl ClosedList {
-	pub fn new(start: ComputeGridNode) -> Self {
-		Self {
-			start,
-			parents: HashMap::from([(start, start)]),
-		}
+	pub fn new(grid: ComputeGrid, start: ComputeGridNode) -> Self {
+		let mut parents = NodeMap::new(grid, None);
+		parents.insert(start, Some(start));
+
+		Self { start, parents }
 	}
 
 	pub fn insert(&mut self, node: ComputeGridNode, comes_from: ComputeGridNode) {
-		self.parents.insert(node, comes_from);
+		self.parents.insert(node, Some(comes_from));
 	}
 
 	pub fn construct_path_from(self, node: ComputeGridNode) -> PathIterator {
@@ -103,7 +259,7 @@ impl ClosedList {
 	}
 
 	pub fn parent(&self, node: &ComputeGridNode) -> Option<&ComputeGridNode> {
-		self.parents.get(node)
+		self.parents.get(node)?.as_ref()
 	}
 }
 
@@ -259,7 +415,7 @@ where
 			})
 			.collect::<Vec<_>>();
 
-		second_pass
+		let mut third_pass = second_pass
 			.iter()
 			.enumerate()
 			.flat_map(|(i, node)| {
@@ -275,7 +431,11 @@ where
 
 				Self::try_override_nodes(los, node, last, next)
 			})
-			.collect()
+			.collect::<Vec<_>>();
+
+		// overrides of adjacent corners can move both onto the same node
+		third_pass.dedup();
+		third_pass
 	}
 }
 
@@ -309,51 +469,126 @@ where
 	}
 }
 
-pub struct OpenList<'a> {
+/// Order of open nodes with the same f.
+#[derive(Debug, PartialEq, Eq, Default, Clone, Copy)]
+pub enum TieBreak {
+	/// Lower node first, by x and then by y
+	#[default]
+	LowerNode,
+	HigherG,
+	LowerH,
+	Insertion,
+}
+
+pub struct OpenList<THeuristic, TCost = f32> {
 	end: ComputeGridNode,
-	heap: BinaryHeap<Reverse<Node>>,
-	dist_f: &'a dyn Fn(ComputeGridNode, ComputeGridNode) -> f32,
+	heap: BinaryHeap<Reverse<Node<TCost>>>,
+	heuristic: THeuristic,
+	weight: f32,
+	tie_break: TieBreak,
+	pushed: u64,
 }
 
-impl<'a> OpenList<'a> {
-	pub fn new(
-		start: ComputeGridNode,
-		end: ComputeGridNode,
-		dist_f: &'a dyn Fn(ComputeGridNode, ComputeGridNode) -> f32,
-	) -> Self {
-		let f = dist_f(start, end);
-		OpenList {
+impl<THeuristic, TCost> OpenList<THeuristic, TCost>
+where
+	THeuristic: Heuristic<TCost>,
+	TCost: Cost,
+{
+	pub fn new(start: ComputeGridNode, end: ComputeGridNode, heuristic: THeuristic) -> Self {
+		let mut open = OpenList {
 			end,
-			dist_f,
-			heap: BinaryHeap::from([Reverse(Node { node: start, f })]),
-		}
+			heuristic,
+			heap: BinaryHeap::new(),
+			weight: 1.,
+			tie_break: TieBreak::default(),
+			pushed: 0,
+		};
+
+		open.push(start, TCost::ZERO);
+		open
+	}
+
+	pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+		self.tie_break = tie_break;
+		self.rebuild(|Node { g, .. }| *g);
+		self
 	}
 
 	pub fn pop_lowest_f(&mut self) -> Option<ComputeGridNode> {
 		self.heap.pop().map(|Reverse(Node { node, .. })| node)
 	}
 
-	pub fn push(&mut self, node: ComputeGridNode, g: f32) {
-		let f = g + (self.dist_f)(node, self.end);
-		self.heap.push(Reverse(Node { node, f }));
+	/// Includes outdated entries, so this is only a lower bound of the f of open nodes.
+	pub fn lowest_f(&self) -> Option<TCost> {
+		self.heap.peek().map(|Reverse(Node { f, .. })| *f)
+	}
+
+	pub fn push(&mut self, node: ComputeGridNode, g: TCost) {
+		self.pushed += 1;
+		self.push_with_sequence(node, g, self.pushed);
+	}
+
+	fn push_with_sequence(&mut self, node: ComputeGridNode, g: TCost, sequence: u64) {
+		let h = self.heuristic.estimate(node, self.end).scale(self.weight);
+		let (tie, sequence) = match self.tie_break {
+			TieBreak::LowerNode => (TCost::ZERO, 0),
+			TieBreak::HigherG => (-g, 0),
+			TieBreak::LowerH => (h, 0),
+			TieBreak::Insertion => (TCost::ZERO, sequence),
+		};
+
+		self.heap.push(Reverse(Node {
+			f: g + h,
+			tie,
+			sequence,
+			node,
+			g,
+		}));
+	}
+
+	fn rebuild(&mut self, g: impl Fn(&Node<TCost>) -> TCost) {
+		let mut nodes = std::mem::take(&mut self.heap).into_vec();
+		nodes.sort_by_key(|Reverse(Node { sequence, .. })| *sequence);
+
+		for Reverse(node) in nodes {
+			self.push_with_sequence(node.node, g(&node), node.sequence);
+		}
+	}
+
+	pub fn nodes(&self) -> impl Iterator<Item = ComputeGridNode> + '_ {
+		self.heap.iter().map(|Reverse(Node { node, .. })| *node)
+	}
+
+	pub fn reweight(&mut self, weight: f32, g_scores: &GScores<TCost>) {
+		self.weight = weight;
+		self.rebuild(|Node { node, .. }| g_scores.get(node));
 	}
 }
 
 #[derive(Debug, PartialEq)]
-struct Node {
+struct Node<TCost> {
+	f: TCost,
+	tie: TCost,
+	sequence: u64,
 	node: ComputeGridNode,
-	f: f32,
+	g: TCost,
 }
 
-impl Eq for Node {}
+impl<TCost> Eq for Node<TCost> where TCost: Cost {}
 
-impl PartialOrd for Node {
+impl<TCost> PartialOrd for Node<TCost>
+where
+	TCost: Cost,
+{
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
 		Some(self.cmp(other))
 	}
 }
 
-impl Ord for Node {
+impl<TCost> Ord for Node<TCost>
+where
+	TCost: Cost,
+{
 	fn cmp(&self, other: &Self) -> Ordering {
 		let Some(c_f) = self.f.partial_cmp(&other.f) else {
 			panic!(
@@ -361,22 +596,190 @@ impl Ord for Node {
 				self, other
 			);
 		};
-		c_f.then_with(|| self.node.cmp(&other.node))
+		c_f.then_with(|| self.tie.total_cmp(&other.tie))
+			.then_with(|| self.sequence.cmp(&other.sequence))
+			.then_with(|| self.node.cmp(&other.node))
 	}
 }
 
-pub struct GScores(HashMap<ComputeGridNode, f32>);
+#[derive(Debug)]
+pub struct GScores<TCost = f32>(NodeMap<TCost>);
+
+impl<TCost> GScores<TCost>
+where
+	TCost: Cost,
+{
+	pub fn new(grid: ComputeGrid, start: ComputeGridNode) -> Self {
+		let mut scores = NodeMap::new(grid, TCost::INFINITY);
+		scores.insert(start, TCost::ZERO);
 
-impl GScores {
-	pub fn new(start: ComputeGridNode) -> Self {
-		Self(HashMap::from([(start, 0.)]))
+		Self(scores)
 	}
 
-	pub fn insert(&mut self, node: ComputeGridNode, score: f32) {
+	pub fn insert(&mut self, node: ComputeGridNode, score: TCost) {
 		self.0.insert(node, score);
 	}
 
-	pub fn get(&self, node: &ComputeGridNode) -> f32 {
-		self.0.get(node).cloned().unwrap_or(f32::INFINITY)
+	pub fn get(&self, node: &ComputeGridNode) -> TCost {
+		self.0.get(node).copied().unwrap_or(TCost::INFINITY)
+	}
+}
+
+#[cfg(test)]
+mod test_sliced {
+	use super::*;
+
+	fn a_star() -> AStar {
+		AStar::new(
+			ComputeGrid {
+				min: ComputeGridNode::new(0, 0),
+				max: ComputeGridNode::new(4, 4),
+			},
+			Obstacles::from_nodes(
+				ComputeGrid {
+					min: ComputeGridNode::new(0, 0),
+					max: ComputeGridNode::new(4, 4),
+				},
+				[ComputeGridNode::new(2, 1), ComputeGridNode::new(2, 2)],
+			),
+		)
+	}
+
+	#[test]
+	fn expand_at_most_budget() {
+		let a_star = a_star();
+		let mut search =
+			a_star.start_search(ComputeGridNode::new(0, 2), ComputeGridNode::new(4, 2));
+
+		assert_eq!(
+			SearchProgress::Pending { expanded: 3 },
+			a_star.continue_search(&mut search, 3)
+		);
+	}
+
+	#[test]
+	fn same_path_when_sliced() {
+		let a_star = a_star();
+		let start = ComputeGridNode::new(0, 2);
+		let end = ComputeGridNode::new(4, 2);
+		let mut search = a_star.start_search(start, end);
+		let mut slices = 1;
+
+		let path = loop {
+			match a_star.continue_search(&mut search, 2) {
+				SearchProgress::Pending { .. } => slices += 1,
+				SearchProgress::Done { path, .. } | SearchProgress::Improved { path, .. } => {
+					break path
+				}
+			}
+		};
+
+		assert_eq!((true, a_star.path(start, end)), (slices > 1, path));
+	}
+}
+
+#[cfg(test)]
+mod test_tie_break {
+	use super::*;
+	use crate::traits::heuristic::Zero;
+
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(9, 9),
+	};
+
+	fn popped(mut open: OpenList<Zero>) -> Vec<ComputeGridNode> {
+		std::iter::from_fn(|| open.pop_lowest_f()).collect()
+	}
+
+	#[test]
+	fn lower_node_first() {
+		let mut open = OpenList::new(ComputeGridNode::new(2, 0), GRID.max, Zero);
+		open.push(ComputeGridNode::new(1, 0), 0.);
+		open.push(ComputeGridNode::new(0, 0), 0.);
+
+		assert_eq!(
+			vec![
+				ComputeGridNode::new(0, 0),
+				ComputeGridNode::new(1, 0),
+				ComputeGridNode::new(2, 0),
+			],
+			popped(open)
+		);
+	}
+
+	#[test]
+	fn insertion_order() {
+		let mut open = OpenList::new(ComputeGridNode::new(2, 0), GRID.max, Zero)
+			.with_tie_break(TieBreak::Insertion);
+		open.push(ComputeGridNode::new(1, 0), 0.);
+		open.push(ComputeGridNode::new(0, 0), 0.);
+
+		assert_eq!(
+			vec![
+				ComputeGridNode::new(2, 0),
+				ComputeGridNode::new(1, 0),
+				ComputeGridNode::new(0, 0),
+			],
+			popped(open)
+		);
+	}
+
+	#[test]
+	fn higher_g_first() {
+		let heuristic = |node: ComputeGridNode, _| 2. - node.x as f32;
+		let mut open = OpenList::new(ComputeGridNode::new(0, 0), GRID.max, heuristic)
+			.with_tie_break(TieBreak::HigherG);
+		open.push(ComputeGridNode::new(2, 0), 2.);
+		open.push(ComputeGridNode::new(1, 0), 1.);
+
+		assert_eq!(Some(ComputeGridNode::new(2, 0)), open.pop_lowest_f());
+	}
+
+	#[test]
+	fn lower_h_first() {
+		let heuristic = |node: ComputeGridNode, _| 2. - node.x as f32;
+		let mut open = OpenList::new(ComputeGridNode::new(0, 0), GRID.max, heuristic)
+			.with_tie_break(TieBreak::LowerH);
+		open.push(ComputeGridNode::new(2, 0), 2.);
+		open.push(ComputeGridNode::new(1, 0), 1.);
+
+		assert_eq!(Some(ComputeGridNode::new(2, 0)), open.pop_lowest_f());
+	}
+
+	#[test]
+	fn keep_insertion_order_when_reweighted() {
+		let mut g_scores = GScores::new(GRID, ComputeGridNode::new(2, 0));
+		g_scores.insert(ComputeGridNode::new(1, 0), 0.);
+		g_scores.insert(ComputeGridNode::new(0, 0), 0.);
+		let mut open = OpenList::new(ComputeGridNode::new(2, 0), GRID.max, Zero)
+			.with_tie_break(TieBreak::Insertion);
+		open.push(ComputeGridNode::new(1, 0), 0.);
+		open.push(ComputeGridNode::new(0, 0), 0.);
+
+		open.reweight(2., &g_scores);
+
+		assert_eq!(
+			vec![
+				ComputeGridNode::new(2, 0),
+				ComputeGridNode::new(1, 0),
+				ComputeGridNode::new(0, 0),
+			],
+			popped(open)
+		);
+	}
+
+	#[test]
+	fn fewer_expansions_preferring_higher_g_on_open_grid() {
+		let start = GRID.min;
+		let end = GRID.max;
+
+		let (expanded, _) = <AStar>::new(GRID, Obstacles::new(GRID))
+			.with_tie_break(TieBreak::HigherG)
+			.path_with_expansions(start, end);
+		let (lower_node_expanded, _) =
+			<AStar>::new(GRID, Obstacles::new(GRID)).path_with_expansions(start, end);
+
+		assert_eq!((Some(18), Some(99)), (expanded, lower_node_expanded));
 	}
 }
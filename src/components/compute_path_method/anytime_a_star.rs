@@ -0,0 +1,413 @@
+use super::a_star::{ClosedList, GScores, OpenList};
+use crate::traits::{
+	computable_grid::{
+		dense::{NodeMap, Obstacles},
+		ComputeGrid,
+		ComputeGridNode,
+	},
+	compute_path::{ComputePath, ComputePathSliced, NewComputer, SearchProgress},
+	cost::Cost,
+	heuristic::{Heuristic, Manhattan, NewHeuristic},
+};
+use std::marker::PhantomData;
+
+/// Anytime repairing A* (ARA*)
+pub struct AnytimeAStar<THeuristic = Manhattan, TCost = f32> {
+	grid: ComputeGrid,
+	obstacles: Obstacles,
+	heuristic: THeuristic,
+	initial_weight: f32,
+	weight_step: f32,
+	_p: PhantomData<TCost>,
+}
+
+impl<THeuristic, TCost> AnytimeAStar<THeuristic, TCost> {
+	pub const DEFAULT_INITIAL_WEIGHT: f32 = 3.;
+	pub const DEFAULT_WEIGHT_STEP: f32 = 0.5;
+	const NEIGHBORS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+
+	/// Steps of 0 or less continue with weight 1 right after the first path.
+	pub fn with_weights(self, initial: f32, step: f32) -> Self {
+		Self {
+			initial_weight: initial.max(1.),
+			weight_step: step,
+			..self
+		}
+	}
+
+	pub fn with_heuristic<TOther>(self, heuristic: TOther) -> AnytimeAStar<TOther, TCost> {
+		AnytimeAStar {
+			grid: self.grid,
+			obstacles: self.obstacles,
+			heuristic,
+			initial_weight: self.initial_weight,
+			weight_step: self.weight_step,
+			_p: PhantomData,
+		}
+	}
+
+	fn neighbors<'a>(
+		&'a self,
+		center: &'a ComputeGridNode,
+	) -> impl Iterator<Item = ComputeGridNode> + 'a {
+		Self::NEIGHBORS
+			.iter()
+			.map(|(x, y)| ComputeGridNode::new(center.x + x, center.y + y))
+			.filter(|node| self.grid.contains(*node))
+	}
+
+	fn next_weight(&self, weight: f32) -> f32 {
+		match self.weight_step > 0. {
+			true => (weight - self.weight_step).max(1.),
+			false => 1.,
+		}
+	}
+
+	/// Derived from the lowest unweighted f of all nodes, that could still lead to a
+	/// cheaper path.
+	fn bound(&self, search: &AnytimeSearch<THeuristic, TCost>) -> f32
+	where
+		THeuristic: Heuristic<TCost>,
+		TCost: Cost,
+	{
+		let g_end = search.g_scores.get(&search.end);
+		if g_end == TCost::ZERO {
+			return 1.;
+		}
+
+		let lowest_f = search
+			.open
+			.nodes()
+			.filter(|node| search.closed.get(node) != Some(&true))
+			.chain(search.inconsistent.iter().copied())
+			.map(|node| search.g_scores.get(&node) + self.heuristic.estimate(node, search.end))
+			.min_by(|a, b| a.total_cmp(b))
+			.unwrap_or(TCost::INFINITY);
+
+		if g_end <= lowest_f {
+			return 1.;
+		}
+		if g_end >= lowest_f.scale(search.weight) {
+			return search.weight;
+		}
+
+		(g_end.to_f32() / lowest_f.to_f32()).clamp(1., search.weight)
+	}
+
+	fn restart(&self, search: &mut AnytimeSearch<THeuristic, TCost>)
+	where
+		THeuristic: Heuristic<TCost>,
+		TCost: Cost,
+	{
+		search.weight = self.next_weight(search.weight);
+		for node in std::mem::take(&mut search.inconsistent) {
+			search.open.push(node, search.g_scores.get(&node));
+		}
+		search.open.reweight(search.weight, &search.g_scores);
+		search.closed = NodeMap::new(self.grid, false);
+	}
+}
+
+impl<THeuristic, TCost> NewComputer for AnytimeAStar<THeuristic, TCost>
+where
+	THeuristic: NewHeuristic,
+{
+	fn new(grid: ComputeGrid, obstacles: Obstacles) -> Self {
+		Self {
+			heuristic: THeuristic::new(grid, &obstacles),
+			grid,
+			obstacles,
+			initial_weight: Self::DEFAULT_INITIAL_WEIGHT,
+			weight_step: Self::DEFAULT_WEIGHT_STEP,
+			_p: PhantomData,
+		}
+	}
+}
+
+impl<THeuristic, TCost> ComputePath for AnytimeAStar<THeuristic, TCost>
+where
+	THeuristic: Heuristic<TCost> + Clone + Send + Sync + 'static,
+	TCost: Cost,
+{
+	fn draw_connections(&self) -> bool {
+		const { true }
+	}
+
+	fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode> {
+		let (_, path) = self.path_with_expansions(start, end);
+		path
+	}
+
+	fn path_with_expansions(
+		&self,
+		start: ComputeGridNode,
+		end: ComputeGridNode,
+	) -> (Option<usize>, Vec<ComputeGridNode>) {
+		let mut search = self.start_search(start, end);
+		let mut total = 0;
+
+		loop {
+			match self.continue_search(&mut search, usize::MAX) {
+				SearchProgress::Pending { expanded }
+				| SearchProgress::Improved { expanded, .. } => {
+					total += expanded;
+				}
+				SearchProgress::Done { expanded, path } => return (Some(total + expanded), path),
+			}
+		}
+	}
+}
+
+impl<THeuristic, TCost> ComputePathSliced for AnytimeAStar<THeuristic, TCost>
+where
+	THeuristic: Heuristic<TCost> + Clone + Send + Sync + 'static,
+	TCost: Cost,
+{
+	type TSearch = AnytimeSearch<THeuristic, TCost>;
+
+	fn start_search(&self, start: ComputeGridNode, end: ComputeGridNode) -> Self::TSearch {
+		let g_scores = GScores::new(self.grid, start);
+		let mut open = OpenList::new(start, end, self.heuristic.clone());
+
+		open.reweight(self.initial_weight, &g_scores);
+
+		AnytimeSearch {
+			end,
+			weight: self.initial_weight,
+			open,
+			closed: NodeMap::new(self.grid, false),
+			inconsistent: vec![],
+			parents: ClosedList::new(self.grid, start),
+			g_scores,
+			reported: (TCost::INFINITY, f32::INFINITY),
+		}
+	}
+
+	fn continue_search(&self, search: &mut Self::TSearch, budget: usize) -> SearchProgress {
+		let mut expanded = 0;
+
+		while expanded < budget {
+			let g_end = search.g_scores.get(&search.end);
+
+			if g_end <= search.open.lowest_f().unwrap_or(TCost::INFINITY) {
+				if g_end == TCost::INFINITY {
+					return SearchProgress::Done {
+						expanded,
+						path: vec![],
+					};
+				}
+
+				let mut path = search
+					.parents
+					.clone()
+					.construct_path_from(search.end)
+					.collect::<Vec<_>>();
+				path.reverse();
+
+				let bound = self.bound(search);
+				if search.weight <= 1. || bound <= 1. {
+					return SearchProgress::Done { expanded, path };
+				}
+
+				self.restart(search);
+				if (g_end, bound) == search.reported {
+					continue;
+				}
+
+				search.reported = (g_end, bound);
+				return SearchProgress::Improved {
+					expanded,
+					path,
+					bound,
+				};
+			}
+
+			let Some(current) = search.open.pop_lowest_f() else {
+				continue;
+			};
+
+			if search.closed.get(&current) == Some(&true) {
+				continue;
+			}
+
+			search.closed.insert(current, true);
+			expanded += 1;
+
+			for neighbor in self.neighbors(&current) {
+				if self.obstacles.contains(&neighbor) {
+					continue;
+				}
+
+				let g = search.g_scores.get(&current) + Manhattan::distance(current, neighbor);
+
+				if g >= search.g_scores.get(&neighbor) {
+					continue;
+				}
+
+				search.parents.insert(neighbor, current);
+				search.g_scores.insert(neighbor, g);
+
+				match search.closed.get(&neighbor) == Some(&true) {
+					true => search.inconsistent.push(neighbor),
+					false => search.open.push(neighbor, g),
+				}
+			}
+		}
+
+		SearchProgress::Pending { expanded }
+	}
+}
+
+pub struct AnytimeSearch<THeuristic = Manhattan, TCost = f32> {
+	end: ComputeGridNode,
+	weight: f32,
+	open: OpenList<THeuristic, TCost>,
+	closed: NodeMap<bool>,
+	/// Nodes, whose g improved after they were expanded with the current weight
+	inconsistent: Vec<ComputeGridNode>,
+	parents: ClosedList,
+	g_scores: GScores<TCost>,
+	reported: (TCost, f32),
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{components::compute_path_method::a_star::AStar, traits::heuristic::Zero};
+
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(6, 6),
+	};
+	const START: ComputeGridNode = ComputeGridNode::new(0, 0);
+	const END: ComputeGridNode = ComputeGridNode::new(6, 6);
+
+	/// The first path found with a weight of 3 takes a detour of 2 steps.
+	fn obstacles() -> Obstacles {
+		Obstacles::from_nodes(
+			GRID,
+			[(2, 2), (3, 4), (4, 6), (5, 1), (5, 3), (5, 4), (5, 5)]
+				.map(|(x, y)| ComputeGridNode::new(x, y)),
+		)
+	}
+
+	fn progress(method: &AnytimeAStar) -> Vec<SearchProgress> {
+		let mut search = method.start_search(START, END);
+		let mut progress = vec![];
+
+		loop {
+			let next = method.continue_search(&mut search, usize::MAX);
+			let done = matches!(next, SearchProgress::Done { .. });
+			progress.push(next);
+			if done {
+				return progress;
+			}
+		}
+	}
+
+	fn cost(path: &[ComputeGridNode]) -> f32 {
+		path.len().saturating_sub(1) as f32
+	}
+
+	#[test]
+	fn optimal_path() {
+		let path = <AnytimeAStar>::new(GRID, obstacles()).path(START, END);
+
+		assert_eq!(
+			<AStar>::new(GRID, obstacles()).path(START, END).len(),
+			path.len()
+		);
+	}
+
+	#[test]
+	fn optimal_path_with_other_heuristic() {
+		let path = <AnytimeAStar>::new(GRID, obstacles())
+			.with_heuristic(Zero)
+			.path(START, END);
+
+		assert_eq!(
+			<AStar>::new(GRID, obstacles()).path(START, END).len(),
+			path.len()
+		);
+	}
+
+	#[test]
+	fn no_path_when_end_enclosed() {
+		let obstacles = Obstacles::from_nodes(
+			GRID,
+			[ComputeGridNode::new(5, 6), ComputeGridNode::new(6, 5)],
+		);
+
+		let path = <AnytimeAStar>::new(GRID, obstacles).path(START, END);
+
+		assert_eq!(vec![] as Vec<ComputeGridNode>, path);
+	}
+
+	#[test]
+	fn improve_detour_until_optimal() {
+		let method = <AnytimeAStar>::new(GRID, obstacles()).with_weights(3., 0.5);
+
+		let costs = progress(&method)
+			.into_iter()
+			.filter_map(|progress| match progress {
+				SearchProgress::Improved { path, .. } | SearchProgress::Done { path, .. } => {
+					Some(cost(&path))
+				}
+				SearchProgress::Pending { .. } => None,
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!((Some(&14.), Some(&12.)), (costs.first(), costs.last()));
+	}
+
+	#[test]
+	fn bounds_hold_and_decrease() {
+		let method = <AnytimeAStar>::new(GRID, obstacles()).with_weights(3., 0.5);
+		let optimal = cost(&<AStar>::new(GRID, obstacles()).path(START, END));
+
+		let bounds = progress(&method)
+			.into_iter()
+			.filter_map(|progress| match progress {
+				SearchProgress::Improved { path, bound, .. } => Some((cost(&path), bound)),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		assert!(
+			!bounds.is_empty()
+				&& bounds
+					.iter()
+					.all(|(cost, bound)| *cost <= bound * optimal && *bound <= 3.)
+				&& bounds.windows(2).all(|pair| pair[0].1 >= pair[1].1),
+			"{:?}",
+			bounds
+		);
+	}
+
+	#[test]
+	fn optimal_after_first_path_when_step_is_zero() {
+		let method = <AnytimeAStar>::new(GRID, obstacles()).with_weights(3., 0.);
+
+		let progress = progress(&method);
+
+		assert!(
+			matches!(
+				progress.as_slice(),
+				[SearchProgress::Improved { .. }, SearchProgress::Done { .. }]
+			),
+			"{:?}",
+			progress
+		);
+	}
+
+	#[test]
+	fn expand_at_most_budget() {
+		let method = <AnytimeAStar>::new(GRID, obstacles());
+		let mut search = method.start_search(START, END);
+
+		assert_eq!(
+			SearchProgress::Pending { expanded: 3 },
+			method.continue_search(&mut search, 3)
+		);
+	}
+}
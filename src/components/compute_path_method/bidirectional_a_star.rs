@@ -0,0 +1,286 @@
+use super::a_star::{ClosedList, GScores, OpenList};
+use crate::traits::{
+	computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+	compute_path::{ComputePath, NewComputer},
+	cost::Cost,
+	heuristic::{Heuristic, Manhattan, NewHeuristic},
+};
+use std::marker::PhantomData;
+
+pub struct BidirectionalAStar<THeuristic = Manhattan, TCost = f32> {
+	grid: ComputeGrid,
+	obstacles: Obstacles,
+	heuristic: THeuristic,
+	_p: PhantomData<TCost>,
+}
+
+impl<THeuristic, TCost> BidirectionalAStar<THeuristic, TCost> {
+	const NEIGHBORS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+
+	pub fn with_heuristic<TOther>(self, heuristic: TOther) -> BidirectionalAStar<TOther, TCost> {
+		BidirectionalAStar {
+			grid: self.grid,
+			obstacles: self.obstacles,
+			heuristic,
+			_p: PhantomData,
+		}
+	}
+
+	fn neighbors<'a>(
+		&'a self,
+		center: &'a ComputeGridNode,
+	) -> impl Iterator<Item = ComputeGridNode> + 'a {
+		Self::NEIGHBORS
+			.iter()
+			.map(|(x, y)| ComputeGridNode::new(center.x + x, center.y + y))
+			.filter(|node| self.grid.contains(*node))
+	}
+
+	fn expand(
+		&self,
+		search: &mut Search<THeuristic, TCost>,
+		other: &Search<THeuristic, TCost>,
+		meeting: &mut Meeting<TCost>,
+	) where
+		THeuristic: Heuristic<TCost>,
+		TCost: Cost,
+	{
+		let Some(current) = search.open.pop_lowest_f() else {
+			return;
+		};
+
+		for neighbor in self.neighbors(&current) {
+			if self.obstacles.contains(&neighbor) {
+				continue;
+			}
+
+			let g = search.g_scores.get(&current) + Manhattan::distance(current, neighbor);
+
+			if g >= search.g_scores.get(&neighbor) {
+				continue;
+			}
+
+			search.open.push(neighbor, g);
+			search.closed.insert(neighbor, current);
+			search.g_scores.insert(neighbor, g);
+
+			let cost = g + other.g_scores.get(&neighbor);
+			if cost < meeting.cost {
+				*meeting = Meeting {
+					node: Some(neighbor),
+					cost,
+				};
+			}
+		}
+	}
+}
+
+impl<THeuristic, TCost> NewComputer for BidirectionalAStar<THeuristic, TCost>
+where
+	THeuristic: NewHeuristic,
+{
+	fn new(grid: ComputeGrid, obstacles: Obstacles) -> Self {
+		Self {
+			heuristic: THeuristic::new(grid, &obstacles),
+			grid,
+			obstacles,
+			_p: PhantomData,
+		}
+	}
+}
+
+impl<THeuristic, TCost> ComputePath for BidirectionalAStar<THeuristic, TCost>
+where
+	THeuristic: Heuristic<TCost> + Clone,
+	TCost: Cost,
+{
+	fn draw_connections(&self) -> bool {
+		const { true }
+	}
+
+	fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode> {
+		let (_, path) = self.path_with_expansions(start, end);
+		path
+	}
+
+	fn path_with_expansions(
+		&self,
+		start: ComputeGridNode,
+		end: ComputeGridNode,
+	) -> (Option<usize>, Vec<ComputeGridNode>) {
+		if start == end {
+			return (Some(0), vec![start]);
+		}
+
+		let mut forward = Search::new(self.grid, start, end, self.heuristic.clone());
+		let mut backward = Search::new(self.grid, end, start, self.heuristic.clone());
+		let mut meeting = Meeting {
+			node: None,
+			cost: TCost::INFINITY,
+		};
+		let mut expanded = 0;
+
+		// Any path not found yet runs through open nodes of both searches, so it cannot
+		// be cheaper than the larger of both lowest f values.
+		while let (Some(f_forward), Some(f_backward)) =
+			(forward.open.lowest_f(), backward.open.lowest_f())
+		{
+			if meeting.cost <= f_forward || meeting.cost <= f_backward {
+				break;
+			}
+
+			match f_forward <= f_backward {
+				true => self.expand(&mut forward, &backward, &mut meeting),
+				false => self.expand(&mut backward, &forward, &mut meeting),
+			}
+			expanded += 1;
+		}
+
+		let Some(node) = meeting.node else {
+			return (Some(expanded), vec![]);
+		};
+
+		let mut path = forward.closed.construct_path_from(node).collect::<Vec<_>>();
+		path.reverse();
+		path.extend(backward.closed.construct_path_from(node).skip(1));
+
+		(Some(expanded), path)
+	}
+}
+
+struct Search<THeuristic, TCost> {
+	open: OpenList<THeuristic, TCost>,
+	closed: ClosedList,
+	g_scores: GScores<TCost>,
+}
+
+impl<THeuristic, TCost> Search<THeuristic, TCost>
+where
+	THeuristic: Heuristic<TCost>,
+	TCost: Cost,
+{
+	fn new(
+		grid: ComputeGrid,
+		start: ComputeGridNode,
+		end: ComputeGridNode,
+		heuristic: THeuristic,
+	) -> Self {
+		Self {
+			open: OpenList::new(start, end, heuristic),
+			closed: ClosedList::new(grid, start),
+			g_scores: GScores::new(grid, start),
+		}
+	}
+}
+
+struct Meeting<TCost> {
+	node: Option<ComputeGridNode>,
+	cost: TCost,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{components::compute_path_method::a_star::AStar, traits::heuristic::Octile};
+
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(9, 4),
+	};
+
+	fn nodes(nodes: &[(i32, i32)]) -> Vec<ComputeGridNode> {
+		nodes
+			.iter()
+			.map(|(x, y)| ComputeGridNode::new(*x, *y))
+			.collect()
+	}
+
+	/// Corridor along y = 2 from x = 1 to x = 8, open at both ends
+	fn corridor() -> Obstacles {
+		let walls = (1..9).flat_map(|x| [ComputeGridNode::new(x, 1), ComputeGridNode::new(x, 3)]);
+		Obstacles::from_nodes(GRID, walls)
+	}
+
+	#[test]
+	fn straight_path() {
+		let method = <BidirectionalAStar>::new(GRID, Obstacles::new(GRID));
+
+		let path = method.path(ComputeGridNode::new(0, 0), ComputeGridNode::new(4, 0));
+
+		assert_eq!(nodes(&[(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)]), path);
+	}
+
+	#[test]
+	fn path_of_start_to_start() {
+		let method = <BidirectionalAStar>::new(GRID, Obstacles::new(GRID));
+
+		let path = method.path(ComputeGridNode::new(2, 2), ComputeGridNode::new(2, 2));
+
+		assert_eq!(nodes(&[(2, 2)]), path);
+	}
+
+	#[test]
+	fn path_through_corridor() {
+		let method = <BidirectionalAStar>::new(GRID, corridor());
+
+		let path = method.path(ComputeGridNode::new(0, 2), ComputeGridNode::new(9, 2));
+
+		assert_eq!(
+			nodes(&[
+				(0, 2),
+				(1, 2),
+				(2, 2),
+				(3, 2),
+				(4, 2),
+				(5, 2),
+				(6, 2),
+				(7, 2),
+				(8, 2),
+				(9, 2)
+			]),
+			path
+		);
+	}
+
+	#[test]
+	fn same_length_as_a_star_around_obstacles() {
+		let obstacles = Obstacles::from_nodes(
+			GRID,
+			nodes(&[(3, 0), (3, 1), (3, 2), (3, 3), (6, 4), (6, 3), (6, 2)]),
+		);
+		let start = ComputeGridNode::new(0, 0);
+		let end = ComputeGridNode::new(9, 4);
+
+		let path = <BidirectionalAStar>::new(GRID, obstacles.clone()).path(start, end);
+		let a_star = <AStar>::new(GRID, obstacles).path(start, end);
+
+		assert_eq!(a_star.len(), path.len());
+	}
+
+	#[test]
+	fn same_length_as_a_star_with_other_heuristic() {
+		let obstacles = Obstacles::from_nodes(
+			GRID,
+			nodes(&[(3, 0), (3, 1), (3, 2), (3, 3), (6, 4), (6, 3), (6, 2)]),
+		);
+		let start = ComputeGridNode::new(0, 0);
+		let end = ComputeGridNode::new(9, 4);
+
+		let path = <BidirectionalAStar>::new(GRID, obstacles.clone())
+			.with_heuristic(Octile)
+			.path(start, end);
+		let a_star = <AStar>::new(GRID, obstacles).path(start, end);
+
+		assert_eq!(a_star.len(), path.len());
+	}
+
+	#[test]
+	fn no_path_when_end_enclosed() {
+		let obstacles = Obstacles::from_nodes(GRID, nodes(&[(8, 4), (8, 3), (9, 3)]));
+		let method = <BidirectionalAStar>::new(GRID, obstacles);
+
+		let path = method.path(ComputeGridNode::new(0, 0), ComputeGridNode::new(9, 4));
+
+		assert_eq!(vec![] as Vec<ComputeGridNode>, path);
+	}
+}
@@ -0,0 +1,474 @@
+use super::a_star::AStar;
+use crate::traits::{
+	computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+	compute_path::NewComputer,
+	space_time_constraints::SpaceTimeConstraints,
+};
+use std::{
+	cmp::Reverse,
+	collections::{BinaryHeap, HashSet},
+	fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Conflict-Based Search from Sharon et al. "Conflict-based search for optimal
+/// multi-agent pathfinding".
+///
+/// Plans each agent on its own with [`AStar`] in space-time. Whenever two plans
+/// collide, the search branches into two constraint sets, each forbidding one of
+/// the agents to be at the conflict. Branches are expanded cheapest first, which
+/// makes the first conflict free set of plans optimal in the sum of arrival times.
+pub struct ConflictBasedSearch {
+	a_star: AStar,
+	max_expansions: usize,
+}
+
+impl ConflictBasedSearch {
+	const DEFAULT_MAX_EXPANSIONS: usize = 2048;
+
+	pub fn with_max_expansions(self, max_expansions: usize) -> Self {
+		Self {
+			max_expansions,
+			..self
+		}
+	}
+
+	/// Conflict free paths, one node per time step, for all agents in the given order.
+	pub fn plan(
+		&self,
+		agents: &[(ComputeGridNode, ComputeGridNode)],
+	) -> Result<Vec<Vec<ComputeGridNode>>, PlanError> {
+		Self::check_shared_nodes(agents)?;
+
+		let root = PlanNode {
+			constraints: vec![Constraints::default(); agents.len()],
+			paths: self.independent_plan(agents),
+		};
+
+		if let Some(agent) = root.paths.iter().position(Vec::is_empty) {
+			return Err(PlanError::Unreachable { agent });
+		}
+
+		let mut nodes = vec![root];
+		let mut open = BinaryHeap::from([Reverse((nodes[0].cost(), 0))]);
+		let mut expansions = 0;
+
+		while let Some(Reverse((_, index))) = open.pop() {
+			let Some(conflict) = conflicts(&nodes[index].paths).into_iter().next() else {
+				return Ok(nodes.swap_remove(index).paths);
+			};
+
+			if expansions >= self.max_expansions {
+				return Err(PlanError::ExpansionLimit);
+			}
+			expansions += 1;
+
+			for (agent, constraint) in conflict.constraints() {
+				let mut child = nodes[index].clone();
+				let (start, end) = agents[agent];
+
+				child.constraints[agent].add(constraint);
+
+				let path = self
+					.a_star
+					.path_in_time(start, end, &child.constraints[agent]);
+				if path.is_empty() {
+					continue;
+				}
+
+				child.paths[agent] = path;
+				open.push(Reverse((child.cost(), nodes.len())));
+				nodes.push(child);
+			}
+		}
+
+		Err(PlanError::ExpansionLimit)
+	}
+
+	/// Agents sharing a start or a goal always collide, constraints cannot resolve that.
+	fn check_shared_nodes(agents: &[(ComputeGridNode, ComputeGridNode)]) -> Result<(), PlanError> {
+		for (a, (a_start, a_end)) in agents.iter().enumerate() {
+			for (b, (b_start, b_end)) in agents.iter().enumerate().skip(a + 1) {
+				if a_start == b_start {
+					return Err(PlanError::SharedStart {
+						agents: [a, b],
+						node: *a_start,
+					});
+				}
+				if a_end == b_end {
+					return Err(PlanError::SharedGoal {
+						agents: [a, b],
+						node: *a_end,
+					});
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Paths of all agents as if each agent was alone on the grid.
+	pub fn independent_plan(
+		&self,
+		agents: &[(ComputeGridNode, ComputeGridNode)],
+	) -> Vec<Vec<ComputeGridNode>> {
+		agents
+			.iter()
+			.map(|(start, end)| self.a_star.path_in_time(*start, *end, &()))
+			.collect()
+	}
+}
+
+impl NewComputer for ConflictBasedSearch {
+	fn new(grid: ComputeGrid, obstacles: Obstacles) -> Self {
+		Self {
+			a_star: AStar::new(grid, obstacles),
+			max_expansions: Self::DEFAULT_MAX_EXPANSIONS,
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PlanError {
+	SharedStart {
+		agents: [usize; 2],
+		node: ComputeGridNode,
+	},
+	SharedGoal {
+		agents: [usize; 2],
+		node: ComputeGridNode,
+	},
+	Unreachable {
+		agent: usize,
+	},
+	ExpansionLimit,
+}
+
+impl Display for PlanError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			PlanError::SharedStart {
+				agents: [a, b],
+				node,
+			} => write!(f, "Agents {a} and {b} start on the same node {node:?}"),
+			PlanError::SharedGoal {
+				agents: [a, b],
+				node,
+			} => write!(f, "Agents {a} and {b} end on the same node {node:?}"),
+			PlanError::Unreachable { agent } => write!(f, "Agent {agent} cannot reach its goal"),
+			PlanError::ExpansionLimit => write!(f, "No conflict free plan within expansion limit"),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+struct PlanNode {
+	constraints: Vec<Constraints>,
+	paths: Vec<Vec<ComputeGridNode>>,
+}
+
+impl PlanNode {
+	fn cost(&self) -> usize {
+		self.paths
+			.iter()
+			.map(|path| path.len().saturating_sub(1))
+			.sum()
+	}
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Conflict {
+	/// Both agents occupy `node` at time step `t`.
+	Node {
+		agents: [usize; 2],
+		node: ComputeGridNode,
+		t: u32,
+	},
+	/// The first agent moves from `from` to `to`, while the second agent moves
+	/// from `to` to `from`, both between time steps `t` and `t + 1`.
+	Edge {
+		agents: [usize; 2],
+		from: ComputeGridNode,
+		to: ComputeGridNode,
+		t: u32,
+	},
+}
+
+impl Conflict {
+	fn constraints(self) -> [(usize, Constraint); 2] {
+		match self {
+			Conflict::Node {
+				agents: [a, b],
+				node,
+				t,
+			} => [
+				(a, Constraint::Node(node, t)),
+				(b, Constraint::Node(node, t)),
+			],
+			Conflict::Edge {
+				agents: [a, b],
+				from,
+				to,
+				t,
+			} => [
+				(a, Constraint::Edge(from, to, t)),
+				(b, Constraint::Edge(to, from, t)),
+			],
+		}
+	}
+
+	/// Node where the conflict happens, for edge conflicts the node the first agent leaves.
+	pub fn node(&self) -> ComputeGridNode {
+		match self {
+			Conflict::Node { node, .. } => *node,
+			Conflict::Edge { from, .. } => *from,
+		}
+	}
+}
+
+/// All conflicts between the given paths ordered by time step.
+///
+/// Agents that arrived remain at their last node.
+pub fn conflicts(paths: &[Vec<ComputeGridNode>]) -> Vec<Conflict> {
+	let duration = paths.iter().map(Vec::len).max().unwrap_or_default();
+	let node_at = |agent: usize, t: usize| paths[agent].get(t).or(paths[agent].last());
+	let mut conflicts = vec![];
+
+	for t in 0..duration {
+		for a in 0..paths.len() {
+			for b in a + 1..paths.len() {
+				let (Some(a_now), Some(b_now)) = (node_at(a, t), node_at(b, t)) else {
+					continue;
+				};
+
+				if a_now == b_now {
+					conflicts.push(Conflict::Node {
+						agents: [a, b],
+						node: *a_now,
+						t: t as u32,
+					});
+					continue;
+				}
+
+				let (Some(a_next), Some(b_next)) = (node_at(a, t + 1), node_at(b, t + 1)) else {
+					continue;
+				};
+
+				if a_now == b_next && b_now == a_next {
+					conflicts.push(Conflict::Edge {
+						agents: [a, b],
+						from: *a_now,
+						to: *a_next,
+						t: t as u32,
+					});
+				}
+			}
+		}
+	}
+
+	conflicts
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Constraint {
+	Node(ComputeGridNode, u32),
+	Edge(ComputeGridNode, ComputeGridNode, u32),
+}
+
+#[derive(Debug, Default, Clone)]
+struct Constraints {
+	nodes: HashSet<(ComputeGridNode, u32)>,
+	edges: HashSet<(ComputeGridNode, ComputeGridNode, u32)>,
+	last: u32,
+}
+
+impl Constraints {
+	fn add(&mut self, constraint: Constraint) {
+		match constraint {
+			Constraint::Node(node, t) => {
+				self.nodes.insert((node, t));
+				self.last = self.last.max(t);
+			}
+			Constraint::Edge(from, to, t) => {
+				self.edges.insert((from, to, t));
+				self.last = self.last.max(t + 1);
+			}
+		}
+	}
+}
+
+impl SpaceTimeConstraints for Constraints {
+	fn allows(&self, from: ComputeGridNode, to: ComputeGridNode, t: u32) -> bool {
+		!self.nodes.contains(&(to, t + 1)) && !self.edges.contains(&(from, to, t))
+	}
+
+	fn last_constrained(&self) -> u32 {
+		self.last
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn search(max_x: i32, max_y: i32, obstacles: &[ComputeGridNode]) -> ConflictBasedSearch {
+		let grid = ComputeGrid {
+			min: ComputeGridNode::new(0, 0),
+			max: ComputeGridNode::new(max_x, max_y),
+		};
+
+		ConflictBasedSearch::new(grid, Obstacles::from_nodes(grid, obstacles.iter().copied()))
+	}
+
+	fn cost(paths: &[Vec<ComputeGridNode>]) -> usize {
+		paths.iter().map(|path| path.len() - 1).sum()
+	}
+
+	#[test]
+	fn detect_node_conflict() {
+		let paths = [
+			vec![ComputeGridNode::new(0, 1), ComputeGridNode::new(1, 1)],
+			vec![ComputeGridNode::new(1, 0), ComputeGridNode::new(1, 1)],
+		];
+
+		assert_eq!(
+			vec![Conflict::Node {
+				agents: [0, 1],
+				node: ComputeGridNode::new(1, 1),
+				t: 1,
+			}],
+			conflicts(&paths)
+		);
+	}
+
+	#[test]
+	fn detect_edge_conflict() {
+		let paths = [
+			vec![ComputeGridNode::new(0, 0), ComputeGridNode::new(1, 0)],
+			vec![ComputeGridNode::new(1, 0), ComputeGridNode::new(0, 0)],
+		];
+
+		assert_eq!(
+			vec![Conflict::Edge {
+				agents: [0, 1],
+				from: ComputeGridNode::new(0, 0),
+				to: ComputeGridNode::new(1, 0),
+				t: 0,
+			}],
+			conflicts(&paths)
+		);
+	}
+
+	#[test]
+	fn detect_conflict_with_arrived_agent() {
+		let paths = [
+			vec![ComputeGridNode::new(1, 0)],
+			vec![
+				ComputeGridNode::new(0, 0),
+				ComputeGridNode::new(1, 0),
+				ComputeGridNode::new(2, 0),
+			],
+		];
+
+		assert_eq!(
+			vec![Conflict::Node {
+				agents: [0, 1],
+				node: ComputeGridNode::new(1, 0),
+				t: 1,
+			}],
+			conflicts(&paths)
+		);
+	}
+
+	#[test]
+	fn plan_conflict_free_when_crossing() {
+		let search = search(2, 2, &[]);
+
+		let paths = search
+			.plan(&[
+				(ComputeGridNode::new(0, 1), ComputeGridNode::new(2, 1)),
+				(ComputeGridNode::new(1, 0), ComputeGridNode::new(1, 2)),
+			])
+			.unwrap();
+
+		assert_eq!((vec![], 5), (conflicts(&paths), cost(&paths)));
+	}
+
+	#[test]
+	fn plan_conflict_free_when_swapping_through_corridor() {
+		let search = search(
+			3,
+			1,
+			&[ComputeGridNode::new(0, 1), ComputeGridNode::new(3, 1)],
+		);
+
+		let paths = search
+			.plan(&[
+				(ComputeGridNode::new(0, 0), ComputeGridNode::new(3, 0)),
+				(ComputeGridNode::new(3, 0), ComputeGridNode::new(0, 0)),
+			])
+			.unwrap();
+
+		assert_eq!((vec![], 8), (conflicts(&paths), cost(&paths)));
+	}
+
+	#[test]
+	fn fail_when_swap_impossible() {
+		let search = search(2, 0, &[]);
+
+		let paths = search.plan(&[
+			(ComputeGridNode::new(0, 0), ComputeGridNode::new(2, 0)),
+			(ComputeGridNode::new(2, 0), ComputeGridNode::new(0, 0)),
+		]);
+
+		assert_eq!(Err(PlanError::ExpansionLimit), paths);
+	}
+
+	#[test]
+	fn fail_up_front_when_agents_share_start() {
+		let search = search(2, 2, &[]).with_max_expansions(usize::MAX);
+
+		let paths = search.plan(&[
+			(ComputeGridNode::new(0, 0), ComputeGridNode::new(2, 0)),
+			(ComputeGridNode::new(1, 1), ComputeGridNode::new(2, 2)),
+			(ComputeGridNode::new(0, 0), ComputeGridNode::new(0, 2)),
+		]);
+
+		assert_eq!(
+			Err(PlanError::SharedStart {
+				agents: [0, 2],
+				node: ComputeGridNode::new(0, 0),
+			}),
+			paths
+		);
+	}
+
+	#[test]
+	fn fail_up_front_when_agents_share_goal() {
+		let search = search(2, 2, &[]).with_max_expansions(usize::MAX);
+
+		let paths = search.plan(&[
+			(ComputeGridNode::new(0, 0), ComputeGridNode::new(2, 2)),
+			(ComputeGridNode::new(2, 0), ComputeGridNode::new(2, 2)),
+		]);
+
+		assert_eq!(
+			Err(PlanError::SharedGoal {
+				agents: [0, 1],
+				node: ComputeGridNode::new(2, 2),
+			}),
+			paths
+		);
+	}
+
+	#[test]
+	fn fail_when_goal_unreachable() {
+		let search = search(3, 0, &[ComputeGridNode::new(1, 0)]);
+
+		let paths = search.plan(&[
+			(ComputeGridNode::new(3, 0), ComputeGridNode::new(2, 0)),
+			(ComputeGridNode::new(0, 0), ComputeGridNode::new(3, 0)),
+		]);
+
+		assert_eq!(Err(PlanError::Unreachable { agent: 1 }), paths);
+	}
+}
@@ -0,0 +1,336 @@
+use crate::traits::{
+	computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+	compute_path::{ComputePath, NewComputer},
+};
+use std::{
+	cmp::Reverse,
+	collections::{BinaryHeap, HashMap, HashSet},
+};
+
+/// Windowed cooperative A* in the spirit of WHCA* from David Silver's
+/// "Cooperative Pathfinding", using the manhattan distance instead of an
+/// abstract search as heuristic.
+///
+/// Agents are planned one after another in space-time, each one avoiding the nodes
+/// and edges reserved by the agents planned before it. Reservations are only honored
+/// within `window` time steps, beyond that an agent plans as if it were alone.
+pub struct CooperativeAStar {
+	grid: ComputeGrid,
+	obstacles: Obstacles,
+	window: u32,
+}
+
+type TimeStep = u32;
+
+impl CooperativeAStar {
+	const DEFAULT_WINDOW: TimeStep = 16;
+	const MOVES: [(i32, i32); 5] = [(0, 0), (0, 1), (0, -1), (1, 0), (-1, 0)];
+
+	pub fn with_window(self, window: TimeStep) -> Self {
+		Self { window, ..self }
+	}
+
+	/// Plans all agents in the given order.
+	///
+	/// Each path holds one node per time step, waiting is expressed by repeating a node.
+	/// Agents that cannot reach their goal get an empty path and do not reserve anything.
+	pub fn plan(&self, agents: &[(ComputeGridNode, ComputeGridNode)]) -> Vec<Vec<ComputeGridNode>> {
+		let mut reservations = ReservationTable::default();
+
+		agents
+			.iter()
+			.map(|(start, end)| {
+				let path = self.path_with(*start, *end, &reservations);
+				reservations.reserve(&path);
+				path
+			})
+			.collect()
+	}
+
+	pub fn path_with(
+		&self,
+		start: ComputeGridNode,
+		end: ComputeGridNode,
+		reservations: &ReservationTable,
+	) -> Vec<ComputeGridNode> {
+		let time_limit = self.window + self.area();
+		let mut open = BinaryHeap::from([Reverse((Self::distance(start, end), 0, start))]);
+		let mut parents =
+			HashMap::<(ComputeGridNode, TimeStep), (ComputeGridNode, TimeStep)>::new();
+		let mut closed = HashSet::new();
+
+		while let Some(Reverse((_, t, current))) = open.pop() {
+			if current == end && reservations.is_free_between(end, t, self.window) {
+				return Self::construct_path(&parents, (current, t));
+			}
+
+			if !closed.insert(self.key(current, t)) || t >= time_limit {
+				continue;
+			}
+
+			for neighbor in self.moves(&current) {
+				let t_next = t + 1;
+
+				if t_next <= self.window && !reservations.allows(current, neighbor, t) {
+					continue;
+				}
+				if closed.contains(&self.key(neighbor, t_next)) {
+					continue;
+				}
+				if parents.contains_key(&(neighbor, t_next)) {
+					continue;
+				}
+
+				parents.insert((neighbor, t_next), (current, t));
+				open.push(Reverse((
+					t_next + Self::distance(neighbor, end),
+					t_next,
+					neighbor,
+				)));
+			}
+		}
+
+		vec![]
+	}
+
+	fn area(&self) -> TimeStep {
+		let width = self.grid.max.x.abs_diff(self.grid.min.x) + 1;
+		let height = self.grid.max.y.abs_diff(self.grid.min.y) + 1;
+		width * height
+	}
+
+	/// Beyond the window all time steps of a node are treated as the same state.
+	fn key(&self, node: ComputeGridNode, t: TimeStep) -> (ComputeGridNode, TimeStep) {
+		(node, t.min(self.window + 1))
+	}
+
+	fn moves<'a>(
+		&'a self,
+		center: &'a ComputeGridNode,
+	) -> impl Iterator<Item = ComputeGridNode> + 'a {
+		Self::MOVES
+			.iter()
+			.map(|(x, y)| ComputeGridNode::new(center.x + x, center.y + y))
+			.filter(|node| {
+				node.x <= self.grid.max.x
+					&& node.x >= self.grid.min.x
+					&& node.y <= self.grid.max.y
+					&& node.y >= self.grid.min.y
+					&& !self.obstacles.contains(node)
+			})
+	}
+
+	fn distance(a: ComputeGridNode, b: ComputeGridNode) -> TimeStep {
+		a.x.abs_diff(b.x) + a.y.abs_diff(b.y)
+	}
+
+	fn construct_path(
+		parents: &HashMap<(ComputeGridNode, TimeStep), (ComputeGridNode, TimeStep)>,
+		mut state: (ComputeGridNode, TimeStep),
+	) -> Vec<ComputeGridNode> {
+		let mut path = vec![state.0];
+
+		while let Some(parent) = parents.get(&state) {
+			path.push(parent.0);
+			state = *parent;
+		}
+
+		path.reverse();
+		path
+	}
+}
+
+impl NewComputer for CooperativeAStar {
+	fn new(grid: ComputeGrid, obstacles: Obstacles) -> Self {
+		Self {
+			grid,
+			obstacles,
+			window: Self::DEFAULT_WINDOW,
+		}
+	}
+}
+
+impl ComputePath for CooperativeAStar {
+	fn draw_connections(&self) -> bool {
+		const { true }
+	}
+
+	fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode> {
+		self.path_with(start, end, &ReservationTable::default())
+	}
+}
+
+/// Space-time reservations of planned agents.
+#[derive(Debug, Default, Clone)]
+pub struct ReservationTable {
+	nodes: HashSet<(ComputeGridNode, TimeStep)>,
+	edges: HashSet<(ComputeGridNode, ComputeGridNode, TimeStep)>,
+	parked: HashMap<ComputeGridNode, TimeStep>,
+}
+
+impl ReservationTable {
+	/// Reserves every node of the path at its time step, the edges between them and
+	/// the final node from the time of arrival onwards.
+	pub fn reserve(&mut self, path: &[ComputeGridNode]) {
+		let Some(goal) = path.last() else {
+			return;
+		};
+
+		for (t, node) in (0..).zip(path) {
+			self.nodes.insert((*node, t));
+		}
+
+		for (t, edge) in (0..).zip(path.windows(2)) {
+			let [from, to] = edge else {
+				continue;
+			};
+			self.edges.insert((*from, *to, t));
+		}
+
+		let arrival = path.len() as TimeStep - 1;
+		self.parked
+			.entry(*goal)
+			.and_modify(|t| *t = (*t).min(arrival))
+			.or_insert(arrival);
+	}
+
+	pub fn is_free(&self, node: ComputeGridNode, t: TimeStep) -> bool {
+		if self.nodes.contains(&(node, t)) {
+			return false;
+		}
+
+		match self.parked.get(&node) {
+			Some(arrival) => t < *arrival,
+			None => true,
+		}
+	}
+
+	/// Moving from `from` at `t` to `to` at `t + 1` neither collides at `to` nor swaps
+	/// places with another agent.
+	pub fn allows(&self, from: ComputeGridNode, to: ComputeGridNode, t: TimeStep) -> bool {
+		self.is_free(to, t + 1) && !self.edges.contains(&(to, from, t))
+	}
+
+	/// Node can be occupied from `from` until `until` without anyone passing through.
+	pub fn is_free_between(&self, node: ComputeGridNode, from: TimeStep, until: TimeStep) -> bool {
+		(from..=until).all(|t| self.is_free(node, t))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn computer(max_x: i32, max_y: i32, obstacles: &[ComputeGridNode]) -> CooperativeAStar {
+		let grid = ComputeGrid {
+			min: ComputeGridNode::new(0, 0),
+			max: ComputeGridNode::new(max_x, max_y),
+		};
+
+		CooperativeAStar::new(grid, Obstacles::from_nodes(grid, obstacles.iter().copied()))
+	}
+
+	fn node_at(path: &[ComputeGridNode], t: usize) -> Option<ComputeGridNode> {
+		path.get(t).or(path.last()).copied()
+	}
+
+	fn collisions(paths: &[Vec<ComputeGridNode>]) -> Vec<(usize, usize, usize)> {
+		let duration = paths.iter().map(Vec::len).max().unwrap_or_default();
+		let mut collisions = vec![];
+
+		for t in 0..duration {
+			for a in 0..paths.len() {
+				for b in a + 1..paths.len() {
+					let same_node = node_at(&paths[a], t) == node_at(&paths[b], t);
+					let swapped = t > 0
+						&& node_at(&paths[a], t) == node_at(&paths[b], t - 1)
+						&& node_at(&paths[b], t) == node_at(&paths[a], t - 1);
+
+					if same_node || swapped {
+						collisions.push((a, b, t));
+					}
+				}
+			}
+		}
+
+		collisions
+	}
+
+	#[test]
+	fn single_agent_takes_shortest_path() {
+		let computer = computer(4, 4, &[]);
+
+		let path = computer.path(ComputeGridNode::new(0, 0), ComputeGridNode::new(3, 2));
+
+		assert_eq!(
+			(
+				6,
+				Some(&ComputeGridNode::new(0, 0)),
+				Some(&ComputeGridNode::new(3, 2))
+			),
+			(path.len(), path.first(), path.last())
+		);
+	}
+
+	#[test]
+	fn avoid_node_conflict_when_crossing() {
+		let computer = computer(2, 2, &[]);
+
+		let paths = computer.plan(&[
+			(ComputeGridNode::new(0, 1), ComputeGridNode::new(2, 1)),
+			(ComputeGridNode::new(1, 0), ComputeGridNode::new(1, 2)),
+		]);
+
+		assert_eq!(
+			(vec![], Some(&ComputeGridNode::new(1, 2))),
+			(collisions(&paths), paths[1].last())
+		);
+	}
+
+	#[test]
+	fn avoid_swapping_places() {
+		let computer = computer(2, 1, &[]);
+
+		let paths = computer.plan(&[
+			(ComputeGridNode::new(0, 0), ComputeGridNode::new(2, 0)),
+			(ComputeGridNode::new(2, 0), ComputeGridNode::new(0, 0)),
+		]);
+
+		assert_eq!(
+			(vec![], Some(&ComputeGridNode::new(0, 0))),
+			(collisions(&paths), paths[1].last())
+		);
+	}
+
+	#[test]
+	fn do_not_pass_through_parked_agent_within_window() {
+		let computer = computer(2, 1, &[ComputeGridNode::new(1, 1)]).with_window(3);
+
+		let paths = computer.plan(&[
+			(ComputeGridNode::new(1, 0), ComputeGridNode::new(1, 0)),
+			(ComputeGridNode::new(0, 0), ComputeGridNode::new(2, 0)),
+		]);
+
+		assert_eq!(
+			Some(4),
+			paths[1]
+				.iter()
+				.position(|node| node == &ComputeGridNode::new(1, 0))
+		);
+	}
+
+	#[test]
+	fn ignore_reservations_beyond_window() {
+		let computer = computer(2, 0, &[]).with_window(0);
+
+		let paths = computer.plan(&[
+			(ComputeGridNode::new(2, 0), ComputeGridNode::new(0, 0)),
+			(ComputeGridNode::new(0, 0), ComputeGridNode::new(1, 0)),
+		]);
+
+		assert_eq!(
+			vec![ComputeGridNode::new(0, 0), ComputeGridNode::new(1, 0)],
+			paths[1]
+		);
+	}
+}
@@ -0,0 +1,399 @@
+use super::{
+	a_star::{ClosedList, GScores, OpenList, TieBreak},
+	straight_line_wide::WideLineOfSight,
+};
+use crate::traits::{
+	computable_grid::{
+		dense::{NodeMap, Obstacles},
+		ComputeGrid,
+		ComputeGridNode,
+	},
+	compute_path::{ComputePath, ComputePathSliced, NewComputer, SearchProgress},
+	cost::Cost,
+	heuristic::{GridDistance, Heuristic, NewHeuristic, Octile},
+	line_of_sight::LineOfSight,
+};
+use std::marker::PhantomData;
+
+/// Line of sight is only checked, when a node is expanded.
+pub struct LazyThetaStar<THeuristic = Octile, TCost = f32, TSight = WideLineOfSight> {
+	grid: ComputeGrid,
+	obstacles: Obstacles,
+	heuristic: THeuristic,
+	tie_break: TieBreak,
+	sight: TSight,
+	_p: PhantomData<TCost>,
+}
+
+impl<THeuristic, TCost, TSight> LazyThetaStar<THeuristic, TCost, TSight>
+where
+	TCost: Cost,
+	TSight: LineOfSight,
+{
+	const NEIGHBORS: &[(i32, i32)] = &[
+		(-1, -1),
+		(-1, 0),
+		(-1, 1),
+		(0, -1),
+		(0, 1),
+		(1, -1),
+		(1, 0),
+		(1, 1),
+	];
+
+	pub fn with_tie_break(self, tie_break: TieBreak) -> Self {
+		Self { tie_break, ..self }
+	}
+
+	pub fn with_line_of_sight<TOther>(
+		self,
+		sight: TOther,
+	) -> LazyThetaStar<THeuristic, TCost, TOther> {
+		LazyThetaStar {
+			grid: self.grid,
+			obstacles: self.obstacles,
+			heuristic: self.heuristic,
+			tie_break: self.tie_break,
+			sight,
+			_p: PhantomData,
+		}
+	}
+
+	fn neighbors<'a>(
+		&'a self,
+		center: &'a ComputeGridNode,
+	) -> impl Iterator<Item = ComputeGridNode> + 'a {
+		Self::NEIGHBORS
+			.iter()
+			.map(|(x, y)| ComputeGridNode::new(center.x + x, center.y + y))
+			.filter(|node| self.grid.contains(*node))
+	}
+
+	fn distance(a: ComputeGridNode, b: ComputeGridNode) -> TCost {
+		Octile::distance(a, b)
+	}
+
+	fn los(&self, a: ComputeGridNode, b: ComputeGridNode) -> bool {
+		self.sight.visible(&self.obstacles, a, b)
+	}
+
+	/// Without line of sight from its parent, `current` is connected to the cheapest
+	/// expanded neighbor it can see.
+	fn set_vertex(
+		&self,
+		closed: &mut ClosedList,
+		g_scores: &mut GScores<TCost>,
+		expanded: &NodeMap<bool>,
+		current: ComputeGridNode,
+	) -> Vertex {
+		let Some(parent) = closed.parent(&current).copied() else {
+			return Vertex::Visible;
+		};
+
+		if self.los(parent, current) {
+			return Vertex::Visible;
+		}
+
+		let cheapest = self
+			.neighbors(&current)
+			.filter(|neighbor| expanded.get(neighbor) == Some(&true))
+			.filter(|neighbor| self.los(*neighbor, current))
+			.map(|neighbor| {
+				(
+					neighbor,
+					g_scores.get(&neighbor) + Self::distance(neighbor, current),
+				)
+			})
+			.min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+		let Some((parent, g)) = cheapest else {
+			g_scores.insert(current, TCost::INFINITY);
+			return Vertex::Hidden;
+		};
+
+		closed.insert(current, parent);
+		g_scores.insert(current, g);
+		Vertex::Corrected
+	}
+}
+
+enum Vertex {
+	Visible,
+	Corrected,
+	Hidden,
+}
+
+impl<THeuristic, TCost, TSight> NewComputer for LazyThetaStar<THeuristic, TCost, TSight>
+where
+	THeuristic: NewHeuristic + GridDistance,
+	TSight: Default,
+{
+	fn new(grid: ComputeGrid, obstacles: Obstacles) -> Self {
+		Self {
+			heuristic: THeuristic::new(grid, &obstacles),
+			grid,
+			obstacles,
+			tie_break: TieBreak::default(),
+			sight: TSight::default(),
+			_p: PhantomData,
+		}
+	}
+}
+
+impl<THeuristic, TCost, TSight> ComputePath for LazyThetaStar<THeuristic, TCost, TSight>
+where
+	THeuristic: Heuristic<TCost> + Clone + Send + Sync + 'static,
+	TCost: Cost,
+	TSight: LineOfSight,
+{
+	fn draw_connections(&self) -> bool {
+		const { true }
+	}
+
+	fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode> {
+		let (_, path) = self.path_with_expansions(start, end);
+		path
+	}
+
+	fn path_with_expansions(
+		&self,
+		start: ComputeGridNode,
+		end: ComputeGridNode,
+	) -> (Option<usize>, Vec<ComputeGridNode>) {
+		let mut search = self.start_search(start, end);
+
+		match self.continue_search(&mut search, usize::MAX) {
+			SearchProgress::Done { expanded, path }
+			| SearchProgress::Improved { expanded, path, .. } => (Some(expanded), path),
+			SearchProgress::Pending { expanded } => (Some(expanded), vec![]),
+		}
+	}
+}
+
+impl<THeuristic, TCost, TSight> ComputePathSliced for LazyThetaStar<THeuristic, TCost, TSight>
+where
+	THeuristic: Heuristic<TCost> + Clone + Send + Sync + 'static,
+	TCost: Cost,
+	TSight: LineOfSight,
+{
+	type TSearch = LazyThetaStarSearch<THeuristic, TCost>;
+
+	fn start_search(&self, start: ComputeGridNode, end: ComputeGridNode) -> Self::TSearch {
+		LazyThetaStarSearch {
+			end,
+			open: OpenList::new(start, end, self.heuristic.clone()).with_tie_break(self.tie_break),
+			closed: ClosedList::new(self.grid, start),
+			g_scores: GScores::new(self.grid, start),
+			expanded: NodeMap::new(self.grid, false),
+		}
+	}
+
+	fn continue_search(&self, search: &mut Self::TSearch, budget: usize) -> SearchProgress {
+		let mut expanded = 0;
+
+		while expanded < budget {
+			let Some(current) = search.open.pop_lowest_f() else {
+				return SearchProgress::Done {
+					expanded,
+					path: vec![],
+				};
+			};
+
+			if search.expanded.get(&current) == Some(&true) {
+				continue;
+			}
+
+			match self.set_vertex(
+				&mut search.closed,
+				&mut search.g_scores,
+				&search.expanded,
+				current,
+			) {
+				Vertex::Visible => {}
+				// With its corrected g, `current` might no longer be the cheapest open node
+				// and other nodes could still offer a better parent.
+				Vertex::Corrected => {
+					search.open.push(current, search.g_scores.get(&current));
+					continue;
+				}
+				// pushed again, once an expanded neighbor can see it
+				Vertex::Hidden => continue,
+			}
+
+			if current == search.end {
+				let closed = std::mem::take(&mut search.closed);
+				let mut path = closed
+					.construct_path_from(current)
+					.remove_redundant_nodes(|a, b| self.los(a, b))
+					.collect_with_optimized_node_positions();
+				path.reverse();
+				return SearchProgress::Done { expanded, path };
+			}
+
+			expanded += 1;
+			search.expanded.insert(current, true);
+
+			let Some(parent) = search.closed.parent(&current).copied() else {
+				continue;
+			};
+
+			for neighbor in self.neighbors(&current) {
+				if search.expanded.get(&neighbor) == Some(&true) {
+					continue;
+				}
+
+				if self.obstacles.contains(&neighbor) {
+					continue;
+				}
+
+				let g = search.g_scores.get(&parent) + Self::distance(parent, neighbor);
+
+				if g >= search.g_scores.get(&neighbor) {
+					continue;
+				}
+
+				search.open.push(neighbor, g);
+				search.closed.insert(neighbor, parent);
+				search.g_scores.insert(neighbor, g);
+			}
+		}
+
+		SearchProgress::Pending { expanded }
+	}
+}
+
+pub struct LazyThetaStarSearch<THeuristic = Octile, TCost = f32> {
+	end: ComputeGridNode,
+	open: OpenList<THeuristic, TCost>,
+	closed: ClosedList,
+	g_scores: GScores<TCost>,
+	expanded: NodeMap<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		components::compute_path_method::{
+			straight_line_wide::WideLineOfSight,
+			theta_star::ThetaStar,
+		},
+		traits::cost::Fixed,
+	};
+	use std::sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	};
+
+	#[derive(Default, Clone)]
+	struct CountingSight(Arc<AtomicUsize>);
+
+	impl CountingSight {
+		fn count(&self) -> usize {
+			self.0.load(Ordering::Relaxed)
+		}
+	}
+
+	impl LineOfSight for CountingSight {
+		fn visible(&self, obstacles: &Obstacles, a: ComputeGridNode, b: ComputeGridNode) -> bool {
+			self.0.fetch_add(1, Ordering::Relaxed);
+			WideLineOfSight.visible(obstacles, a, b)
+		}
+	}
+
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(6, 4),
+	};
+
+	#[test]
+	fn straight_line_on_open_grid() {
+		let method = <LazyThetaStar>::new(GRID, Obstacles::new(GRID));
+
+		let path = method.path(ComputeGridNode::new(0, 0), ComputeGridNode::new(6, 3));
+
+		assert_eq!(
+			vec![ComputeGridNode::new(0, 0), ComputeGridNode::new(6, 3)],
+			path
+		);
+	}
+
+	#[test]
+	fn same_path_as_theta_star_around_corner() {
+		let obstacles = Obstacles::from_nodes(
+			GRID,
+			[
+				ComputeGridNode::new(3, 0),
+				ComputeGridNode::new(3, 1),
+				ComputeGridNode::new(3, 2),
+			],
+		);
+		let start = ComputeGridNode::new(0, 0);
+		let end = ComputeGridNode::new(6, 0);
+
+		let path = <LazyThetaStar>::new(GRID, obstacles.clone()).path(start, end);
+
+		assert_eq!(<ThetaStar>::new(GRID, obstacles).path(start, end), path);
+	}
+
+	#[test]
+	fn same_path_as_theta_star_around_corner_with_fixed_costs() {
+		let obstacles = Obstacles::from_nodes(
+			GRID,
+			[
+				ComputeGridNode::new(3, 0),
+				ComputeGridNode::new(3, 1),
+				ComputeGridNode::new(3, 2),
+			],
+		);
+		let start = ComputeGridNode::new(0, 0);
+		let end = ComputeGridNode::new(6, 0);
+
+		let path = LazyThetaStar::<Octile, Fixed>::new(GRID, obstacles.clone()).path(start, end);
+
+		assert_eq!(<ThetaStar>::new(GRID, obstacles).path(start, end), path);
+	}
+
+	#[test]
+	fn fewer_line_of_sight_checks_than_theta_star() {
+		let grid = ComputeGrid {
+			min: ComputeGridNode::new(0, 0),
+			max: ComputeGridNode::new(19, 19),
+		};
+		let obstacles = Obstacles::from_nodes(grid, (0..15).map(|y| ComputeGridNode::new(10, y)));
+		let start = ComputeGridNode::new(0, 0);
+		let end = ComputeGridNode::new(19, 0);
+
+		let lazy_checks = CountingSight::default();
+		let theta_checks = CountingSight::default();
+
+		let lazy_path = <LazyThetaStar>::new(grid, obstacles.clone())
+			.with_line_of_sight(lazy_checks.clone())
+			.path(start, end);
+		let theta_path = <ThetaStar>::new(grid, obstacles)
+			.with_line_of_sight(theta_checks.clone())
+			.path(start, end);
+
+		assert_eq!(
+			(theta_path, true),
+			(lazy_path, lazy_checks.count() < theta_checks.count())
+		);
+	}
+
+	#[test]
+	fn no_path_when_end_enclosed() {
+		let obstacles = Obstacles::from_nodes(
+			GRID,
+			[
+				ComputeGridNode::new(5, 4),
+				ComputeGridNode::new(5, 3),
+				ComputeGridNode::new(6, 3),
+			],
+		);
+		let method = <LazyThetaStar>::new(GRID, obstacles);
+
+		let path = method.path(ComputeGridNode::new(0, 0), ComputeGridNode::new(6, 4));
+
+		assert_eq!(vec![] as Vec<ComputeGridNode>, path);
+	}
+}
@@ -1,13 +1,13 @@
 use crate::traits::{
-	computable_grid::{ComputeGrid, ComputeGridNode},
+	computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
 	compute_path::{ComputePath, NewComputer},
 };
-use std::{collections::HashSet, ops::RangeInclusive};
+use std::ops::RangeInclusive;
 
 pub struct StraightLine;
 
 impl NewComputer for StraightLine {
-	fn new(_: ComputeGrid, _: HashSet<ComputeGridNode>) -> Self {
+	fn new(_: ComputeGrid, _: Obstacles) -> Self {
 		StraightLine
 	}
 }
@@ -18,7 +18,11 @@ impl ComputePath for StraightLine {
 	}
 
 	fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode> {
-		Line::new(start, end).collect()
+		let mut path = Line::new(start, end).collect::<Vec<_>>();
+		if path.first() != Some(&start) {
+			path.reverse();
+		}
+		path
 	}
 }
 
@@ -1,13 +1,14 @@
 use crate::traits::{
-	computable_grid::{ComputeGrid, ComputeGridNode},
+	computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
 	compute_path::{ComputePath, NewComputer},
+	line_of_sight::LineOfSight,
 };
-use std::{collections::HashSet, ops::RangeInclusive};
+use std::ops::RangeInclusive;
 
 pub struct StraightLineWide;
 
 impl NewComputer for StraightLineWide {
-	fn new(_: ComputeGrid, _: HashSet<ComputeGridNode>) -> Self {
+	fn new(_: ComputeGrid, _: Obstacles) -> Self {
 		StraightLineWide
 	}
 }
@@ -22,6 +23,16 @@ impl ComputePath for StraightLineWide {
 	}
 }
 
+/// Checks the nodes of a [`LineWide`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WideLineOfSight;
+
+impl LineOfSight for WideLineOfSight {
+	fn visible(&self, obstacles: &Obstacles, a: ComputeGridNode, b: ComputeGridNode) -> bool {
+		LineWide::new(a, b).all(|n| !obstacles.contains(&n))
+	}
+}
+
 /// Uses my epic Bresenham's line algorithm hack.
 ///
 /// This adds all nodes to the graph, that would
@@ -1,20 +1,31 @@
 use super::{
-	a_star::{ClosedList, GScores, OpenList},
-	straight_line_wide::LineWide,
+	a_star::{ClosedList, GScores, OpenList, TieBreak},
+	straight_line_wide::WideLineOfSight,
 };
 use crate::traits::{
-	computable_grid::{ComputeGrid, ComputeGridNode},
-	compute_path::{ComputePath, NewComputer},
+	computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+	compute_path::{ComputePath, ComputePathSliced, NewComputer, SearchProgress},
+	cost::Cost,
+	heuristic::{GridDistance, Heuristic, NewHeuristic, Octile},
+	line_of_sight::LineOfSight,
 };
-use std::collections::HashSet;
+use std::marker::PhantomData;
 
-pub struct ThetaStar {
-	sqrt_2: f32,
+/// Lines cost their octile distance.
+pub struct ThetaStar<THeuristic = Octile, TCost = f32, TSight = WideLineOfSight> {
 	grid: ComputeGrid,
-	obstacles: HashSet<ComputeGridNode>,
+	obstacles: Obstacles,
+	heuristic: THeuristic,
+	tie_break: TieBreak,
+	sight: TSight,
+	_p: PhantomData<TCost>,
 }
 
-impl ThetaStar {
+impl<THeuristic, TCost, TSight> ThetaStar<THeuristic, TCost, TSight>
+where
+	TCost: Cost,
+	TSight: LineOfSight,
+{
 	const NEIGHBORS: &[(i32, i32)] = &[
 		(-1, -1),
 		(-1, 0),
@@ -26,6 +37,21 @@ impl ThetaStar {
 		(1, 1),
 	];
 
+	pub fn with_tie_break(self, tie_break: TieBreak) -> Self {
+		Self { tie_break, ..self }
+	}
+
+	pub fn with_line_of_sight<TOther>(self, sight: TOther) -> ThetaStar<THeuristic, TCost, TOther> {
+		ThetaStar {
+			grid: self.grid,
+			obstacles: self.obstacles,
+			heuristic: self.heuristic,
+			tie_break: self.tie_break,
+			sight,
+			_p: PhantomData,
+		}
+	}
+
 	fn neighbors<'a>(
 		&'a self,
 		center: &'a ComputeGridNode,
@@ -41,27 +67,21 @@ impl ThetaStar {
 			})
 	}
 
-	fn distance(&self, a: ComputeGridNode, b: ComputeGridNode) -> f32 {
-		let d_x = a.x.abs_diff(b.x) as f32;
-		let d_y = a.y.abs_diff(b.y) as f32;
-		let (long, short) = match d_x > d_y {
-			true => (d_x, d_y),
-			false => (d_y, d_x),
-		};
-		self.sqrt_2 * short + (long - short)
+	fn distance(a: ComputeGridNode, b: ComputeGridNode) -> TCost {
+		Octile::distance(a, b)
 	}
 
 	fn los(&self, a: ComputeGridNode, b: ComputeGridNode) -> bool {
-		LineWide::new(a, b).all(|n| !self.obstacles.contains(&n))
+		self.sight.visible(&self.obstacles, a, b)
 	}
 
 	fn vertex(
 		&self,
 		closed: &ClosedList,
-		g_scores: &GScores,
+		g_scores: &GScores<TCost>,
 		current: ComputeGridNode,
 		neighbor: ComputeGridNode,
-	) -> Option<(ComputeGridNode, f32)> {
+	) -> Option<(ComputeGridNode, TCost)> {
 		match closed.parent(&current) {
 			Some(parent) if self.los(*parent, neighbor) => self.relax(g_scores, *parent, neighbor),
 			_ if self.los(current, neighbor) => self.relax(g_scores, current, neighbor),
@@ -71,11 +91,11 @@ impl ThetaStar {
 
 	fn relax(
 		&self,
-		g_scores: &GScores,
+		g_scores: &GScores<TCost>,
 		current: ComputeGridNode,
 		neighbor: ComputeGridNode,
-	) -> Option<(ComputeGridNode, f32)> {
-		let g = g_scores.get(&current) + self.distance(current, neighbor);
+	) -> Option<(ComputeGridNode, TCost)> {
+		let g = g_scores.get(&current) + Self::distance(current, neighbor);
 
 		if g >= g_scores.get(&neighbor) {
 			return None;
@@ -85,51 +105,147 @@ impl ThetaStar {
 	}
 }
 
-impl NewComputer for ThetaStar {
-	fn new(grid: ComputeGrid, obstacles: HashSet<ComputeGridNode>) -> Self {
+impl<THeuristic, TCost, TSight> NewComputer for ThetaStar<THeuristic, TCost, TSight>
+where
+	THeuristic: NewHeuristic + GridDistance,
+	TSight: Default,
+{
+	fn new(grid: ComputeGrid, obstacles: Obstacles) -> Self {
 		Self {
+			heuristic: THeuristic::new(grid, &obstacles),
 			grid,
 			obstacles,
-			sqrt_2: 2_f32.sqrt(),
+			tie_break: TieBreak::default(),
+			sight: TSight::default(),
+			_p: PhantomData,
 		}
 	}
 }
 
-impl ComputePath for ThetaStar {
+impl<THeuristic, TCost, TSight> ComputePath for ThetaStar<THeuristic, TCost, TSight>
+where
+	THeuristic: Heuristic<TCost> + Clone + Send + Sync + 'static,
+	TCost: Cost,
+	TSight: LineOfSight,
+{
 	fn draw_connections(&self) -> bool {
 		const { true }
 	}
 
 	fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode> {
-		let dist_f = |a, b| self.distance(a, b);
-		let los_f = |a, b| self.los(a, b);
-		let mut open = OpenList::new(start, end, &dist_f);
-		let mut closed = ClosedList::new(start);
-		let mut g_scores = GScores::new(start);
-
-		while let Some(current) = open.pop_lowest_f() {
-			if current == end {
-				return closed
+		let (_, path) = self.path_with_expansions(start, end);
+		path
+	}
+
+	fn path_with_expansions(
+		&self,
+		start: ComputeGridNode,
+		end: ComputeGridNode,
+	) -> (Option<usize>, Vec<ComputeGridNode>) {
+		let mut search = self.start_search(start, end);
+
+		match self.continue_search(&mut search, usize::MAX) {
+			SearchProgress::Done { expanded, path }
+			| SearchProgress::Improved { expanded, path, .. } => (Some(expanded), path),
+			SearchProgress::Pending { expanded } => (Some(expanded), vec![]),
+		}
+	}
+}
+
+impl<THeuristic, TCost, TSight> ComputePathSliced for ThetaStar<THeuristic, TCost, TSight>
+where
+	THeuristic: Heuristic<TCost> + Clone + Send + Sync + 'static,
+	TCost: Cost,
+	TSight: LineOfSight,
+{
+	type TSearch = ThetaStarSearch<THeuristic, TCost>;
+
+	fn start_search(&self, start: ComputeGridNode, end: ComputeGridNode) -> Self::TSearch {
+		ThetaStarSearch {
+			end,
+			open: OpenList::new(start, end, self.heuristic.clone()).with_tie_break(self.tie_break),
+			closed: ClosedList::new(self.grid, start),
+			g_scores: GScores::new(self.grid, start),
+		}
+	}
+
+	fn continue_search(&self, search: &mut Self::TSearch, budget: usize) -> SearchProgress {
+		let mut expanded = 0;
+
+		while expanded < budget {
+			let Some(current) = search.open.pop_lowest_f() else {
+				return SearchProgress::Done {
+					expanded,
+					path: vec![],
+				};
+			};
+
+			if current == search.end {
+				let closed = std::mem::take(&mut search.closed);
+				let mut path = closed
 					.construct_path_from(current)
-					.remove_redundant_nodes(los_f)
+					.remove_redundant_nodes(|a, b| self.los(a, b))
 					.collect_with_optimized_node_positions();
+				path.reverse();
+				return SearchProgress::Done { expanded, path };
 			}
 
+			expanded += 1;
+
 			for neighbor in self.neighbors(&current) {
 				if self.obstacles.contains(&neighbor) {
 					continue;
 				}
 
-				let Some((current, g)) = self.vertex(&closed, &g_scores, current, neighbor) else {
+				let Some((current, g)) =
+					self.vertex(&search.closed, &search.g_scores, current, neighbor)
+				else {
 					continue;
 				};
 
-				open.push(neighbor, g);
-				closed.insert(neighbor, current);
-				g_scores.insert(neighbor, g);
+				search.open.push(neighbor, g);
+				search.closed.insert(neighbor, current);
+				search.g_scores.insert(neighbor, g);
 			}
 		}
 
-		vec![]
+		SearchProgress::Pending { expanded }
+	}
+}
+
+pub struct ThetaStarSearch<THeuristic = Octile, TCost = f32> {
+	end: ComputeGridNode,
+	open: OpenList<THeuristic, TCost>,
+	closed: ClosedList,
+	g_scores: GScores<TCost>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn no_duplicate_nodes_when_adjacent_corners_are_optimized() {
+		let grid = ComputeGrid {
+			min: ComputeGridNode::new(0, 0),
+			max: ComputeGridNode::new(4, 10),
+		};
+		let obstacles = Obstacles::from_nodes(
+			grid,
+			[
+				ComputeGridNode::new(3, 9),
+				ComputeGridNode::new(2, 7),
+				ComputeGridNode::new(0, 6),
+				ComputeGridNode::new(4, 5),
+				ComputeGridNode::new(1, 4),
+				ComputeGridNode::new(2, 4),
+				ComputeGridNode::new(3, 4),
+			],
+		);
+
+		let path = <ThetaStar>::new(grid, obstacles)
+			.path(ComputeGridNode::new(4, 9), ComputeGridNode::new(4, 0));
+
+		assert!(path.windows(2).all(|pair| pair[0] != pair[1]), "{path:?}");
 	}
 }
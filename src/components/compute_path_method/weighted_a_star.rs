@@ -0,0 +1,203 @@
+use super::a_star::{ClosedList, GScores, OpenList, TieBreak};
+use crate::traits::{
+	computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+	compute_path::{ComputePath, NewComputer},
+	cost::Cost,
+	heuristic::{Heuristic, Manhattan, NewHeuristic},
+};
+use std::marker::PhantomData;
+
+/// Paths cost at most `weight` times as much as the shortest path.
+pub struct WeightedAStar<THeuristic = Manhattan, TCost = f32> {
+	grid: ComputeGrid,
+	obstacles: Obstacles,
+	weight: f32,
+	heuristic: THeuristic,
+	tie_break: TieBreak,
+	_p: PhantomData<TCost>,
+}
+
+impl<THeuristic, TCost> WeightedAStar<THeuristic, TCost> {
+	pub const DEFAULT_WEIGHT: f32 = 1.5;
+	const NEIGHBORS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+
+	pub fn with_weight(self, weight: f32) -> Self {
+		Self {
+			weight: weight.max(1.),
+			..self
+		}
+	}
+
+	pub fn with_tie_break(self, tie_break: TieBreak) -> Self {
+		Self { tie_break, ..self }
+	}
+
+	pub fn with_heuristic<TOther>(self, heuristic: TOther) -> WeightedAStar<TOther, TCost> {
+		WeightedAStar {
+			grid: self.grid,
+			obstacles: self.obstacles,
+			weight: self.weight,
+			heuristic,
+			tie_break: self.tie_break,
+			_p: PhantomData,
+		}
+	}
+
+	fn neighbors<'a>(
+		&'a self,
+		center: &'a ComputeGridNode,
+	) -> impl Iterator<Item = ComputeGridNode> + 'a {
+		Self::NEIGHBORS
+			.iter()
+			.map(|(x, y)| ComputeGridNode::new(center.x + x, center.y + y))
+			.filter(|node| self.grid.contains(*node))
+	}
+}
+
+impl<THeuristic, TCost> NewComputer for WeightedAStar<THeuristic, TCost>
+where
+	THeuristic: NewHeuristic,
+{
+	fn new(grid: ComputeGrid, obstacles: Obstacles) -> Self {
+		Self {
+			heuristic: THeuristic::new(grid, &obstacles),
+			grid,
+			obstacles,
+			weight: Self::DEFAULT_WEIGHT,
+			tie_break: TieBreak::default(),
+			_p: PhantomData,
+		}
+	}
+}
+
+impl<THeuristic, TCost> ComputePath for WeightedAStar<THeuristic, TCost>
+where
+	THeuristic: Heuristic<TCost> + Clone,
+	TCost: Cost,
+{
+	fn draw_connections(&self) -> bool {
+		const { true }
+	}
+
+	fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode> {
+		let (_, path) = self.path_with_expansions(start, end);
+		path
+	}
+
+	fn path_with_expansions(
+		&self,
+		start: ComputeGridNode,
+		end: ComputeGridNode,
+	) -> (Option<usize>, Vec<ComputeGridNode>) {
+		let mut open =
+			OpenList::new(start, end, self.heuristic.clone()).with_tie_break(self.tie_break);
+		let mut closed = ClosedList::new(self.grid, start);
+		let mut g_scores = GScores::new(self.grid, start);
+		let mut expanded = 0;
+
+		open.reweight(self.weight, &g_scores);
+
+		while let Some(current) = open.pop_lowest_f() {
+			if current == end {
+				let mut path = closed.construct_path_from(current).collect::<Vec<_>>();
+				path.reverse();
+				return (Some(expanded), path);
+			}
+
+			expanded += 1;
+
+			for neighbor in self.neighbors(&current) {
+				if self.obstacles.contains(&neighbor) {
+					continue;
+				}
+
+				let g = g_scores.get(&current) + Manhattan::distance(current, neighbor);
+
+				if g >= g_scores.get(&neighbor) {
+					continue;
+				}
+
+				open.push(neighbor, g);
+				closed.insert(neighbor, current);
+				g_scores.insert(neighbor, g);
+			}
+		}
+
+		(Some(expanded), vec![])
+	}
+
+	fn suboptimality_bound(&self) -> Option<f32> {
+		Some(self.weight)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::compute_path_method::a_star::AStar;
+
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(9, 9),
+	};
+
+	fn obstacles() -> Obstacles {
+		let wall = (0..8).map(|y| ComputeGridNode::new(5, y));
+		Obstacles::from_nodes(GRID, wall)
+	}
+
+	#[test]
+	fn raise_weight_to_at_least_one() {
+		let method = <WeightedAStar>::new(GRID, obstacles()).with_weight(0.5);
+
+		assert_eq!(Some(1.), method.suboptimality_bound());
+	}
+
+	#[test]
+	fn same_path_as_a_star_with_weight_one() {
+		let start = ComputeGridNode::new(0, 0);
+		let end = ComputeGridNode::new(9, 0);
+
+		let path = <WeightedAStar>::new(GRID, obstacles())
+			.with_weight(1.)
+			.path(start, end);
+
+		assert_eq!(<AStar>::new(GRID, obstacles()).path(start, end), path);
+	}
+
+	#[test]
+	fn fewer_expansions_than_a_star() {
+		let start = ComputeGridNode::new(0, 0);
+		let end = ComputeGridNode::new(9, 0);
+
+		let (expanded, _) = <WeightedAStar>::new(GRID, obstacles())
+			.with_weight(3.)
+			.path_with_expansions(start, end);
+		let (a_star_expanded, _) = <AStar>::new(GRID, obstacles()).path_with_expansions(start, end);
+
+		assert!(
+			expanded < a_star_expanded,
+			"{:?} >= {:?}",
+			expanded,
+			a_star_expanded
+		);
+	}
+
+	#[test]
+	fn path_within_bound() {
+		let start = ComputeGridNode::new(0, 0);
+		let end = ComputeGridNode::new(9, 0);
+
+		let path = <WeightedAStar>::new(GRID, obstacles())
+			.with_weight(3.)
+			.path(start, end);
+		let a_star = <AStar>::new(GRID, obstacles()).path(start, end);
+
+		assert!(
+			path.len() - 1 <= 3 * (a_star.len() - 1),
+			"{} > 3 * {}",
+			path.len() - 1,
+			a_star.len() - 1
+		);
+	}
+}
@@ -1,4 +1,8 @@
-use super::use_asset::UseAsset;
+use super::{despawn::Despawn, grid_context::GridContext, use_asset::UseAsset};
+use crate::traits::{
+	computable_grid::{ComputeGridNode, GetComputeGridNode},
+	compute_path::validity::{Moves, PathValidity},
+};
 use bevy::prelude::*;
 use std::path::Path;
 
@@ -35,6 +39,32 @@ impl ComputedPath {
 			}
 		}
 	}
+
+	/// Marks the computed paths of `grid` for despawning.
+	pub fn despawn_of(
+		commands: &mut Commands,
+		grid: Entity,
+		computed_paths: &Query<(Entity, &Parent), With<Self>>,
+	) {
+		for (child, parent) in computed_paths {
+			if parent.get() != grid {
+				continue;
+			}
+			let Some(mut child) = commands.get_entity(child) else {
+				continue;
+			};
+			child.try_insert(Despawn::NextFrame);
+		}
+	}
+}
+
+/// Grid nodes of a [`ComputedPath`] and of the route it was requested for, spawned
+/// alongside of it.
+#[derive(Component, Debug, PartialEq, Default, Clone)]
+pub struct ComputedRoute {
+	/// Start, waypoints and end
+	pub route: Vec<ComputeGridNode>,
+	pub path: Vec<ComputeGridNode>,
 }
 
 #[derive(Component, Debug, PartialEq, Default)]
@@ -108,6 +138,78 @@ impl PathNodeConnection {
 			));
 		}
 	}
+
+	/// Marks connections with [`BlockedConnection`], while the [`LineWide`] between their
+	/// nodes crosses or grazes obstacles of the current [`GridContext`], and removes the
+	/// mark once it does not anymore.
+	///
+	/// [`LineWide`]: crate::components::compute_path_method::straight_line_wide::LineWide
+	pub fn mark_blocked<TGrid>(
+		mut commands: Commands,
+		connections: Query<(Entity, &Parent, Has<BlockedConnection>), With<Self>>,
+		nodes: Query<(&PathNode, &Transform, &Parent)>,
+		parents: Query<&Parent>,
+		contexts: Query<&GridContext<TGrid>>,
+		grids: Res<Assets<TGrid>>,
+	) where
+		TGrid: Asset + GetComputeGridNode,
+	{
+		for (entity, node, is_marked) in &connections {
+			let Some(is_blocked) =
+				Self::is_blocked(node.get(), &nodes, &parents, &contexts, &grids)
+			else {
+				continue;
+			};
+			if is_blocked == is_marked {
+				continue;
+			}
+			let Some(mut entity) = commands.get_entity(entity) else {
+				continue;
+			};
+
+			match is_blocked {
+				true => entity.try_insert((BlockedConnection, BlockedConnection::asset())),
+				false => entity
+					.remove::<BlockedConnection>()
+					.try_insert(PathNode::asset::<ColorMaterial>()),
+			};
+		}
+	}
+
+	fn is_blocked<TGrid>(
+		node: Entity,
+		nodes: &Query<(&PathNode, &Transform, &Parent)>,
+		parents: &Query<&Parent>,
+		contexts: &Query<&GridContext<TGrid>>,
+		grids: &Assets<TGrid>,
+	) -> Option<bool>
+	where
+		TGrid: Asset + GetComputeGridNode,
+	{
+		let (PathNode { previous, .. }, transform, path) = nodes.get(node).ok()?;
+		let (_, previous_transform, _) = nodes.get((*previous)?).ok()?;
+		let context = contexts.get(parents.get(path.get()).ok()?.get()).ok()?;
+		let grid = grids.get(&context.handle)?;
+		let a = grid.compute_grid_node(previous_transform.translation.xy())?;
+		let b = grid.compute_grid_node(transform.translation.xy())?;
+		let validity = PathValidity {
+			grid: context.grid,
+			obstacles: &context.obstacles,
+			moves: Moves::LineOfSight,
+		};
+
+		Some(!validity.is_line_of_sight(a, b))
+	}
+}
+
+/// [`PathNodeConnection`] crossing or grazing obstacles, drawn in a warning color.
+#[derive(Component, Debug, PartialEq)]
+pub struct BlockedConnection;
+
+impl BlockedConnection {
+	fn asset() -> UseAsset<ColorMaterial> {
+		UseAsset::new(Path::new("path_node_connection_blocked.json"))
+	}
 }
 
 #[cfg(test)]
@@ -481,3 +583,209 @@ mod test_draw_node_connection {
 		assert_count!(0, entities.filter(is::<PathNodeConnection>));
 	}
 }
+
+#[cfg(test)]
+mod test_mark_blocked_connections {
+	use super::*;
+	use crate::{
+		new_handle,
+		test_tools::SingleThreaded,
+		traits::computable_grid::{dense::Obstacles, ComputeGrid},
+	};
+
+	#[derive(Asset, TypePath)]
+	struct _Grid;
+
+	impl GetComputeGridNode for _Grid {
+		fn compute_grid_node(&self, Vec2 { x, y }: Vec2) -> Option<ComputeGridNode> {
+			Some(ComputeGridNode::new(x as i32, y as i32))
+		}
+	}
+
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(3, 3),
+	};
+
+	fn setup() -> (App, Handle<_Grid>) {
+		let mut app = App::new().single_threaded(Update);
+		let handle = new_handle!(_Grid);
+		let mut grids = Assets::default();
+
+		grids.insert(&handle, _Grid);
+		app.insert_resource(grids);
+		app.add_systems(Update, PathNodeConnection::mark_blocked::<_Grid>);
+
+		(app, handle)
+	}
+
+	/// Spawns a path from `a` to `b` on a grid with `obstacles` and returns the grid
+	/// and the connection.
+	fn spawn_path(
+		app: &mut App,
+		handle: Handle<_Grid>,
+		obstacles: &[ComputeGridNode],
+		a: Vec3,
+		b: Vec3,
+	) -> (Entity, Entity) {
+		let grid = app
+			.world_mut()
+			.spawn(GridContext {
+				handle,
+				grid: GRID,
+				obstacles: Obstacles::from_nodes(GRID, obstacles.iter().copied()),
+			})
+			.id();
+		let path = app.world_mut().spawn(ComputedPath::default()).id();
+		let node_a = app
+			.world_mut()
+			.spawn((
+				PathNode {
+					previous: None,
+					draw_connection: true,
+				},
+				Transform::from_translation(a),
+			))
+			.id();
+		let node_b = app
+			.world_mut()
+			.spawn((
+				PathNode {
+					previous: Some(node_a),
+					draw_connection: true,
+				},
+				Transform::from_translation(b),
+			))
+			.id();
+		let connection = app.world_mut().spawn(PathNodeConnection).id();
+
+		app.world_mut().entity_mut(grid).add_child(path);
+		app.world_mut()
+			.entity_mut(path)
+			.add_children(&[node_a, node_b]);
+		app.world_mut().entity_mut(node_b).add_child(connection);
+
+		(grid, connection)
+	}
+
+	#[test]
+	fn mark_connection_through_obstacle() {
+		let (mut app, handle) = setup();
+		let (_, connection) = spawn_path(
+			&mut app,
+			handle,
+			&[ComputeGridNode::new(1, 0)],
+			Vec3::new(0., 0., 1.),
+			Vec3::new(3., 0., 1.),
+		);
+
+		app.update();
+
+		let connection = app.world().entity(connection);
+		assert_eq!(
+			(Some(&BlockedConnection), Some(&BlockedConnection::asset())),
+			(
+				connection.get::<BlockedConnection>(),
+				connection.get::<UseAsset<ColorMaterial>>()
+			)
+		);
+	}
+
+	#[test]
+	fn mark_connection_grazing_obstacle() {
+		let (mut app, handle) = setup();
+		let (_, connection) = spawn_path(
+			&mut app,
+			handle,
+			&[ComputeGridNode::new(0, 1)],
+			Vec3::new(0., 0., 1.),
+			Vec3::new(2., 1., 1.),
+		);
+
+		app.update();
+
+		assert_eq!(
+			Some(&BlockedConnection),
+			app.world().entity(connection).get::<BlockedConnection>()
+		);
+	}
+
+	#[test]
+	fn do_not_mark_clear_connection() {
+		let (mut app, handle) = setup();
+		let (_, connection) = spawn_path(
+			&mut app,
+			handle,
+			&[ComputeGridNode::new(1, 1)],
+			Vec3::new(0., 0., 1.),
+			Vec3::new(3., 0., 1.),
+		);
+
+		app.update();
+
+		let connection = app.world().entity(connection);
+		assert_eq!(
+			(None, Some(&PathNode::asset::<ColorMaterial>())),
+			(
+				connection.get::<BlockedConnection>(),
+				connection.get::<UseAsset<ColorMaterial>>()
+			)
+		);
+	}
+
+	#[test]
+	fn mark_connection_when_obstacle_added() {
+		let (mut app, handle) = setup();
+		let (grid, connection) = spawn_path(
+			&mut app,
+			handle,
+			&[],
+			Vec3::new(0., 0., 1.),
+			Vec3::new(3., 0., 1.),
+		);
+
+		app.update();
+		app.world_mut()
+			.entity_mut(grid)
+			.get_mut::<GridContext<_Grid>>()
+			.unwrap()
+			.obstacles
+			.insert(ComputeGridNode::new(2, 0));
+		app.update();
+
+		assert_eq!(
+			Some(&BlockedConnection),
+			app.world().entity(connection).get::<BlockedConnection>()
+		);
+	}
+
+	#[test]
+	fn unmark_connection_when_obstacle_removed() {
+		let (mut app, handle) = setup();
+		let (grid, connection) = spawn_path(
+			&mut app,
+			handle,
+			&[ComputeGridNode::new(2, 0)],
+			Vec3::new(0., 0., 1.),
+			Vec3::new(3., 0., 1.),
+		);
+
+		app.update();
+		app.world_mut()
+			.entity_mut(grid)
+			.get_mut::<GridContext<_Grid>>()
+			.unwrap()
+			.obstacles
+			.remove(&ComputeGridNode::new(2, 0));
+		app.update();
+
+		let connection = app.world().entity(connection);
+		assert_eq!(
+			(None, Some(&PathNode::asset::<ColorMaterial>())),
+			(
+				connection.get::<BlockedConnection>(),
+				connection.get::<UseAsset<ColorMaterial>>()
+			)
+		);
+	}
+}
@@ -0,0 +1,488 @@
+use super::{
+	compute_path_method::a_star::{GScores, OpenList},
+	grid_context::GridContext,
+	tile::Tile,
+	tile_type::{TileType, TileTypeValue},
+	use_asset::UseAsset,
+};
+use crate::traits::computable_grid::{
+	dense::Obstacles,
+	ComputeGrid,
+	ComputeGridNode,
+	GetComputeGridNode,
+};
+use bevy::prelude::*;
+use std::{collections::HashMap, path::Path};
+
+/// Direction per cell towards a single goal.
+///
+/// Costs are integrated with Dijkstra from the goal outwards, so all units
+/// heading to the same goal can share one field instead of searching on their own.
+#[derive(Component, Debug, PartialEq, Default)]
+pub struct FlowField {
+	goal: Option<ComputeGridNode>,
+	directions: HashMap<ComputeGridNode, ComputeGridNode>,
+}
+
+impl FlowField {
+	const NEIGHBORS: &[(i32, i32)] = &[
+		(-1, -1),
+		(-1, 0),
+		(-1, 1),
+		(0, -1),
+		(0, 1),
+		(1, -1),
+		(1, 0),
+		(1, 1),
+	];
+
+	pub fn new(grid: ComputeGrid, obstacles: &Obstacles, goal: ComputeGridNode) -> Self {
+		let costs = Self::integrate(grid, obstacles, goal);
+		let mut directions = HashMap::new();
+
+		for x in grid.min.x..=grid.max.x {
+			for y in grid.min.y..=grid.max.y {
+				let node = ComputeGridNode::new(x, y);
+				if node == goal || costs.get(&node) == f32::INFINITY {
+					continue;
+				}
+
+				let lowest = Self::moves(grid, obstacles, node)
+					.map(|(neighbor, step)| (neighbor, costs.get(&neighbor) + step))
+					.min_by(|(a, a_cost), (b, b_cost)| {
+						a_cost.total_cmp(b_cost).then_with(|| a.cmp(b))
+					});
+				let Some((neighbor, _)) = lowest else {
+					continue;
+				};
+
+				directions.insert(node, neighbor - node);
+			}
+		}
+
+		Self {
+			goal: Some(goal),
+			directions,
+		}
+	}
+
+	pub fn goal(&self) -> Option<ComputeGridNode> {
+		self.goal
+	}
+
+	/// Unit step towards the goal, `None` for the goal itself and unreachable cells.
+	pub fn direction(&self, node: &ComputeGridNode) -> Option<ComputeGridNode> {
+		self.directions.get(node).copied()
+	}
+
+	fn integrate(grid: ComputeGrid, obstacles: &Obstacles, goal: ComputeGridNode) -> GScores {
+		let no_heuristic = |_, _| 0.;
+		let mut open = OpenList::new(goal, goal, &no_heuristic);
+		let mut costs = GScores::new(grid, goal);
+
+		while let Some(current) = open.pop_lowest_f() {
+			for (neighbor, cost) in Self::moves(grid, obstacles, current) {
+				let g = costs.get(&current) + cost;
+
+				if g >= costs.get(&neighbor) {
+					continue;
+				}
+
+				open.push(neighbor, g);
+				costs.insert(neighbor, g);
+			}
+		}
+
+		costs
+	}
+
+	/// Legal moves from `node`, diagonal moves must not cut obstacle corners.
+	fn moves<'a>(
+		grid: ComputeGrid,
+		obstacles: &'a Obstacles,
+		node: ComputeGridNode,
+	) -> impl Iterator<Item = (ComputeGridNode, f32)> + 'a {
+		let is_free = move |node: &ComputeGridNode| {
+			node.x >= grid.min.x
+				&& node.x <= grid.max.x
+				&& node.y >= grid.min.y
+				&& node.y <= grid.max.y
+				&& !obstacles.contains(node)
+		};
+
+		Self::NEIGHBORS.iter().filter_map(move |(x, y)| {
+			let neighbor = ComputeGridNode::new(node.x + x, node.y + y);
+
+			if !is_free(&neighbor) {
+				return None;
+			}
+
+			if x == &0 || y == &0 {
+				return Some((neighbor, 1.));
+			}
+
+			let cuts_corner = !is_free(&ComputeGridNode::new(node.x + x, node.y))
+				|| !is_free(&ComputeGridNode::new(node.x, node.y + y));
+
+			match cuts_corner {
+				true => None,
+				false => Some((neighbor, std::f32::consts::SQRT_2)),
+			}
+		})
+	}
+
+	pub fn compute<TGrid>(
+		mut commands: Commands,
+		grids: Res<Assets<TGrid>>,
+		contexts: Query<(Entity, Ref<GridContext<TGrid>>, &Children)>,
+		tiles: Query<(&Transform, Ref<TileType>)>,
+	) where
+		TGrid: Asset + GetComputeGridNode,
+	{
+		for (entity, context, children) in &contexts {
+			let tiles_changed = children
+				.iter()
+				.filter_map(|child| tiles.get(*child).ok())
+				.any(|(_, tile_type)| tile_type.is_changed());
+
+			if !context.is_changed() && !tiles_changed {
+				continue;
+			}
+
+			let Some(grid) = grids.get(&context.handle) else {
+				continue;
+			};
+			let goal = children
+				.iter()
+				.filter_map(|child| tiles.get(*child).ok())
+				.find(|(_, tile_type)| ***tile_type == TileTypeValue::End)
+				.and_then(|(transform, _)| grid.compute_grid_node(transform.translation.xy()));
+			let field = match goal {
+				Some(goal) => FlowField::new(context.grid, &context.obstacles, goal),
+				None => FlowField::default(),
+			};
+			let Some(mut entity) = commands.get_entity(entity) else {
+				continue;
+			};
+
+			entity.try_insert(field);
+		}
+	}
+
+	pub fn draw<TGrid>(
+		mut commands: Commands,
+		grids: Res<Assets<TGrid>>,
+		fields: Query<(&Self, &GridContext<TGrid>, &Children), Changed<Self>>,
+		tiles: Query<(&Transform, Option<&Children>), With<Tile>>,
+		arrows: Query<(), With<FlowArrow>>,
+	) where
+		TGrid: Asset + GetComputeGridNode,
+	{
+		for (field, context, children) in &fields {
+			let Some(grid) = grids.get(&context.handle) else {
+				continue;
+			};
+
+			for child in children {
+				let Ok((transform, tile_children)) = tiles.get(*child) else {
+					continue;
+				};
+
+				despawn_arrows(&mut commands, tile_children, &arrows);
+
+				let Some(node) = grid.compute_grid_node(transform.translation.xy()) else {
+					continue;
+				};
+				let Some(ComputeGridNode { x, y }) = field.direction(&node) else {
+					continue;
+				};
+				let Some(mut tile) = commands.get_entity(*child) else {
+					continue;
+				};
+				let direction = Vec3::new(x as f32, y as f32, 0.);
+
+				tile.with_child((
+					FlowArrow,
+					Transform::from_xyz(0., 0., 0.5).looking_to(Vec3::Z, direction),
+				));
+			}
+		}
+	}
+}
+
+fn despawn_arrows(
+	commands: &mut Commands,
+	children: Option<&Children>,
+	arrows: &Query<(), With<FlowArrow>>,
+) {
+	let Some(children) = children else {
+		return;
+	};
+
+	for child in children.iter().filter(|child| arrows.contains(**child)) {
+		let Some(child) = commands.get_entity(*child) else {
+			continue;
+		};
+		child.despawn_recursive();
+	}
+}
+
+#[derive(Component, Debug, PartialEq)]
+#[require(
+	Transform,
+	Visibility,
+	UseAsset<Mesh>(Self::asset),
+	UseAsset<ColorMaterial>(Self::asset)
+)]
+pub struct FlowArrow;
+
+impl FlowArrow {
+	fn asset<TAsset>() -> UseAsset<TAsset>
+	where
+		TAsset: Asset,
+	{
+		UseAsset::new(Path::new("flow_arrow.json"))
+	}
+}
+
+#[cfg(test)]
+mod test_field {
+	use super::*;
+
+	fn grid(max_x: i32, max_y: i32) -> ComputeGrid {
+		ComputeGrid {
+			min: ComputeGridNode::new(0, 0),
+			max: ComputeGridNode::new(max_x, max_y),
+		}
+	}
+
+	#[test]
+	fn point_straight_towards_goal() {
+		let field = FlowField::new(
+			grid(2, 0),
+			&Obstacles::new(grid(2, 0)),
+			ComputeGridNode::new(2, 0),
+		);
+
+		assert_eq!(
+			[
+				Some(ComputeGridNode::new(1, 0)),
+				Some(ComputeGridNode::new(1, 0)),
+				None
+			],
+			[0, 1, 2].map(|x| field.direction(&ComputeGridNode::new(x, 0)))
+		);
+	}
+
+	#[test]
+	fn point_diagonally_towards_goal() {
+		let field = FlowField::new(
+			grid(2, 2),
+			&Obstacles::new(grid(2, 2)),
+			ComputeGridNode::new(2, 2),
+		);
+
+		assert_eq!(
+			Some(ComputeGridNode::new(1, 1)),
+			field.direction(&ComputeGridNode::new(0, 0))
+		);
+	}
+
+	#[test]
+	fn do_not_cut_obstacle_corners() {
+		let field = FlowField::new(
+			grid(1, 1),
+			&Obstacles::from_nodes(grid(1, 1), [ComputeGridNode::new(1, 0)]),
+			ComputeGridNode::new(1, 1),
+		);
+
+		assert_eq!(
+			Some(ComputeGridNode::new(0, 1)),
+			field.direction(&ComputeGridNode::new(0, 0))
+		);
+	}
+
+	#[test]
+	fn include_step_costs_next_to_diagonal_obstacle() {
+		let field = FlowField::new(
+			grid(4, 4),
+			&Obstacles::from_nodes(grid(4, 4), [ComputeGridNode::new(1, 0)]),
+			ComputeGridNode::new(0, 0),
+		);
+
+		assert_eq!(
+			Some(ComputeGridNode::new(-1, 0)),
+			field.direction(&ComputeGridNode::new(4, 0))
+		);
+	}
+
+	#[test]
+	fn no_direction_for_unreachable_cells() {
+		let field = FlowField::new(
+			grid(2, 0),
+			&Obstacles::from_nodes(grid(2, 0), [ComputeGridNode::new(1, 0)]),
+			ComputeGridNode::new(2, 0),
+		);
+
+		assert_eq!(None, field.direction(&ComputeGridNode::new(0, 0)));
+	}
+
+	#[test]
+	fn no_direction_for_obstacles() {
+		let field = FlowField::new(
+			grid(2, 0),
+			&Obstacles::from_nodes(grid(2, 0), [ComputeGridNode::new(1, 0)]),
+			ComputeGridNode::new(2, 0),
+		);
+
+		assert_eq!(None, field.direction(&ComputeGridNode::new(1, 0)));
+	}
+}
+
+#[cfg(test)]
+mod test_systems {
+	use super::*;
+	use crate::{assert_count, new_handle, test_tools::SingleThreaded};
+
+	#[derive(Asset, TypePath)]
+	struct _Grid;
+
+	impl GetComputeGridNode for _Grid {
+		fn compute_grid_node(&self, Vec2 { x, y }: Vec2) -> Option<ComputeGridNode> {
+			Some(ComputeGridNode::new(x as i32, y as i32))
+		}
+	}
+
+	fn setup(handle: &Handle<_Grid>) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut grids = Assets::default();
+
+		grids.insert(handle, _Grid);
+		app.insert_resource(grids);
+		app.add_systems(
+			Update,
+			(FlowField::compute::<_Grid>, FlowField::draw::<_Grid>).chain(),
+		);
+
+		app
+	}
+
+	fn context(handle: Handle<_Grid>) -> GridContext<_Grid> {
+		let grid = ComputeGrid {
+			min: ComputeGridNode::new(0, 0),
+			max: ComputeGridNode::new(2, 0),
+		};
+
+		GridContext {
+			handle,
+			grid,
+			obstacles: Obstacles::new(grid),
+		}
+	}
+
+	fn is<TComponent>(entity: &EntityRef) -> bool
+	where
+		TComponent: Component,
+	{
+		entity.contains::<TComponent>()
+	}
+
+	#[test]
+	fn insert_field_towards_end_tile() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let entity = app
+			.world_mut()
+			.spawn(context(handle))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(2., 0., 0.),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(ComputeGridNode::new(2, 0)),
+			app.world()
+				.entity(entity)
+				.get::<FlowField>()
+				.and_then(FlowField::goal)
+		);
+	}
+
+	#[test]
+	fn insert_empty_field_without_end_tile() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let entity = app
+			.world_mut()
+			.spawn(context(handle))
+			.with_child((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::from_xyz(2., 0., 0.),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&FlowField::default()),
+			app.world().entity(entity).get::<FlowField>()
+		);
+	}
+
+	#[test]
+	fn spawn_arrows_on_tiles_with_direction() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn(context(handle))
+			.with_child((Tile, Transform::from_xyz(0., 0., 0.)))
+			.with_child((Tile, Transform::from_xyz(1., 0., 0.)))
+			.with_child((
+				Tile,
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(2., 0., 0.),
+			));
+
+		app.update();
+
+		let arrows = assert_count!(2, app.world().iter_entities().filter(is::<FlowArrow>));
+		assert_eq!(
+			[
+				Some(&Transform::from_xyz(0., 0., 0.5).looking_to(Vec3::Z, Vec3::X)),
+				Some(&Transform::from_xyz(0., 0., 0.5).looking_to(Vec3::Z, Vec3::X)),
+			],
+			arrows.map(|arrow| arrow.get::<Transform>())
+		);
+	}
+
+	#[test]
+	fn replace_arrows_when_end_removed() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let entity = app
+			.world_mut()
+			.spawn(context(handle))
+			.with_child((Tile, Transform::from_xyz(0., 0., 0.)))
+			.id();
+		let end = app
+			.world_mut()
+			.spawn((
+				Tile,
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(2., 0., 0.),
+			))
+			.set_parent(entity)
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(end)
+			.insert(TileType::from_value(TileTypeValue::Walkable));
+		app.update();
+
+		assert_count!(0, app.world().iter_entities().filter(is::<FlowArrow>));
+	}
+}
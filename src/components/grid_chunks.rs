@@ -0,0 +1,471 @@
+use super::{
+	grid_context::GridContext,
+	tile::Tile,
+	tile_type::{TileType, TileTypeValue},
+};
+use crate::{
+	assets::chunked_grid::ChunkedGrid,
+	traits::computable_grid::{
+		chunked::ChunkedObstacles,
+		ComputeGridNode,
+		GetComputeGridNode,
+		GetTranslation,
+	},
+};
+use bevy::prelude::*;
+
+/// Loaded tile chunks of a [`ChunkedGrid`] together with the obstacles of all chunks,
+/// that were loaded at some point.
+///
+/// The [`GridContext<ChunkedGrid>`] is kept in sync with the loaded chunks, so that
+/// path searches can cross chunk boundaries.
+#[derive(Component, Debug, PartialEq, Default)]
+pub struct GridChunks {
+	obstacles: ChunkedObstacles,
+}
+
+/// Marks tiles spawned for `chunk`.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub struct ChunkTile {
+	pub chunk: ComputeGridNode,
+}
+
+impl GridChunks {
+	/// Loads chunks within [`ChunkedGrid::load_distance`] around the `TCamera` and
+	/// unloads all others by despawning their tiles.
+	pub fn load_around<TCamera>(
+		mut commands: Commands,
+		grids: Res<Assets<ChunkedGrid>>,
+		cameras: Query<&Transform, With<TCamera>>,
+		mut grid_chunks: Query<(Entity, &mut Self, &GridContext<ChunkedGrid>)>,
+		tiles: Query<(Entity, &ChunkTile, &Parent)>,
+	) where
+		TCamera: Component,
+	{
+		let Ok(camera) = cameras.get_single() else {
+			return;
+		};
+
+		for (entity, mut chunks, context) in &mut grid_chunks {
+			let Some(grid) = grids.get(&context.handle) else {
+				continue;
+			};
+
+			if chunks.obstacles.size() != grid.chunk_size {
+				despawn_tiles(&mut commands, entity, &tiles, |_| true);
+				chunks.obstacles = ChunkedObstacles::new(grid.chunk_size);
+			}
+
+			let Some(center) = grid.compute_grid_node(camera.translation.xy()) else {
+				continue;
+			};
+			let wanted = chunks_around(chunks.obstacles.chunk(center), grid.load_distance);
+			let unload = chunks
+				.obstacles
+				.loaded()
+				.filter(|chunk| !wanted.contains(chunk))
+				.collect::<Vec<_>>();
+			let load = wanted
+				.into_iter()
+				.filter(|chunk| !chunks.obstacles.is_loaded(chunk))
+				.collect::<Vec<_>>();
+
+			if unload.is_empty() && load.is_empty() {
+				continue;
+			}
+
+			despawn_tiles(&mut commands, entity, &tiles, |chunk| {
+				unload.contains(chunk)
+			});
+			for chunk in &unload {
+				chunks.obstacles.unload(chunk);
+			}
+
+			for chunk in load {
+				chunks.obstacles.load(chunk);
+				spawn_tiles(&mut commands, entity, grid, &chunks.obstacles, chunk);
+			}
+		}
+	}
+
+	pub fn track_obstacles(
+		mut grid_chunks: Query<(&mut Self, &GridContext<ChunkedGrid>)>,
+		grids: Res<Assets<ChunkedGrid>>,
+		tiles: Query<(&Transform, &TileType, &Parent), Changed<TileType>>,
+	) {
+		for (transform, tile_type, parent) in &tiles {
+			let Ok((mut chunks, context)) = grid_chunks.get_mut(parent.get()) else {
+				continue;
+			};
+			let Some(grid) = grids.get(&context.handle) else {
+				continue;
+			};
+			let Some(node) = grid.compute_grid_node(transform.translation.xy()) else {
+				continue;
+			};
+			let is_obstacle = **tile_type == TileTypeValue::Obstacle;
+
+			if chunks.obstacles.contains(&node) == is_obstacle {
+				continue;
+			}
+
+			match is_obstacle {
+				true => chunks.obstacles.insert(node),
+				false => chunks.obstacles.remove(&node),
+			};
+		}
+	}
+
+	pub fn update_context(
+		mut grid_chunks: Query<(&Self, &mut GridContext<ChunkedGrid>), Changed<Self>>,
+	) {
+		for (chunks, mut context) in &mut grid_chunks {
+			context.grid = chunks.obstacles.grid();
+			context.obstacles = chunks.obstacles.obstacles();
+		}
+	}
+}
+
+fn chunks_around(center: ComputeGridNode, distance: u32) -> Vec<ComputeGridNode> {
+	let distance = distance as i32;
+
+	(-distance..=distance)
+		.flat_map(|x| (-distance..=distance).map(move |y| center + ComputeGridNode::new(x, y)))
+		.collect()
+}
+
+fn despawn_tiles(
+	commands: &mut Commands,
+	entity: Entity,
+	tiles: &Query<(Entity, &ChunkTile, &Parent)>,
+	unload: impl Fn(&ComputeGridNode) -> bool,
+) {
+	for (tile, ChunkTile { chunk }, parent) in tiles {
+		if parent.get() != entity || !unload(chunk) {
+			continue;
+		}
+		let Some(tile) = commands.get_entity(tile) else {
+			continue;
+		};
+		tile.despawn_recursive();
+	}
+}
+
+fn spawn_tiles(
+	commands: &mut Commands,
+	entity: Entity,
+	grid: &ChunkedGrid,
+	obstacles: &ChunkedObstacles,
+	chunk: ComputeGridNode,
+) {
+	let Some(mut entity) = commands.get_entity(entity) else {
+		return;
+	};
+	let chunk_grid = obstacles.chunk_grid(chunk);
+
+	for x in chunk_grid.min.x..=chunk_grid.max.x {
+		for y in chunk_grid.min.y..=chunk_grid.max.y {
+			let node = ComputeGridNode::new(x, y);
+			let Some(translation) = grid.translation(node) else {
+				continue;
+			};
+			let tile_type = match obstacles.contains(&node) {
+				true => TileType::from_value(TileTypeValue::Obstacle),
+				false => TileType::from_value(TileTypeValue::Walkable),
+			};
+
+			entity.with_child((
+				Tile,
+				ChunkTile { chunk },
+				tile_type,
+				Transform::from_translation(translation.extend(0.)),
+			));
+		}
+	}
+}
+
+#[cfg(test)]
+mod test_load_around {
+	use super::*;
+	use crate::{assert_count, new_handle, test_tools::SingleThreaded};
+
+	#[derive(Component)]
+	struct _Camera;
+
+	fn setup(handle: &Handle<ChunkedGrid>) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut grids = Assets::default();
+
+		grids.insert(
+			handle,
+			ChunkedGrid {
+				chunk_size: 2,
+				load_distance: 1,
+				scale: 1.,
+			},
+		);
+		app.insert_resource(grids);
+		app.add_systems(Update, GridChunks::load_around::<_Camera>);
+
+		app
+	}
+
+	fn chunks_of(app: &App) -> Vec<ComputeGridNode> {
+		let mut chunks = app
+			.world()
+			.iter_entities()
+			.filter_map(|entity| entity.get::<ChunkTile>().map(|tile| tile.chunk))
+			.collect::<Vec<_>>();
+		chunks.sort();
+		chunks.dedup();
+		chunks
+	}
+
+	fn is<TComponent>(entity: &EntityRef) -> bool
+	where
+		TComponent: Component,
+	{
+		entity.contains::<TComponent>()
+	}
+
+	#[test]
+	fn spawn_tiles_of_chunks_around_camera() {
+		let handle = new_handle!(ChunkedGrid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn((GridChunks::default(), GridContext::from_handle(handle)));
+		app.world_mut()
+			.spawn((_Camera, Transform::from_xyz(2., 3., 0.)));
+
+		app.update();
+
+		assert_count!(36, app.world().iter_entities().filter(is::<Tile>));
+		assert_eq!(
+			vec![
+				ComputeGridNode::new(0, 0),
+				ComputeGridNode::new(0, 1),
+				ComputeGridNode::new(0, 2),
+				ComputeGridNode::new(1, 0),
+				ComputeGridNode::new(1, 1),
+				ComputeGridNode::new(1, 2),
+				ComputeGridNode::new(2, 0),
+				ComputeGridNode::new(2, 1),
+				ComputeGridNode::new(2, 2),
+			],
+			chunks_of(&app)
+		);
+	}
+
+	#[test]
+	fn despawn_tiles_of_chunks_out_of_distance() {
+		let handle = new_handle!(ChunkedGrid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn((GridChunks::default(), GridContext::from_handle(handle)));
+		let camera = app
+			.world_mut()
+			.spawn((_Camera, Transform::from_xyz(0., 0., 0.)))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(camera)
+			.insert(Transform::from_xyz(2., 0., 0.));
+		app.update();
+
+		assert_count!(36, app.world().iter_entities().filter(is::<Tile>));
+		assert_eq!(
+			vec![
+				ComputeGridNode::new(0, -1),
+				ComputeGridNode::new(0, 0),
+				ComputeGridNode::new(0, 1),
+				ComputeGridNode::new(1, -1),
+				ComputeGridNode::new(1, 0),
+				ComputeGridNode::new(1, 1),
+				ComputeGridNode::new(2, -1),
+				ComputeGridNode::new(2, 0),
+				ComputeGridNode::new(2, 1),
+			],
+			chunks_of(&app)
+		);
+	}
+
+	#[test]
+	fn do_not_spawn_tiles_again_when_camera_stays_in_chunk() {
+		let handle = new_handle!(ChunkedGrid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn((GridChunks::default(), GridContext::from_handle(handle)));
+		let camera = app
+			.world_mut()
+			.spawn((_Camera, Transform::from_xyz(0., 0., 0.)))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(camera)
+			.insert(Transform::from_xyz(1., 1., 0.));
+		app.update();
+
+		assert_count!(36, app.world().iter_entities().filter(is::<Tile>));
+	}
+
+	#[test]
+	fn spawn_stored_obstacles() {
+		let handle = new_handle!(ChunkedGrid);
+		let mut app = setup(&handle);
+		let mut obstacles = ChunkedObstacles::new(2);
+		obstacles.insert(ComputeGridNode::new(1, 1));
+		app.world_mut()
+			.spawn((GridChunks { obstacles }, GridContext::from_handle(handle)));
+		app.world_mut()
+			.spawn((_Camera, Transform::from_xyz(0., 0., 0.)));
+
+		app.update();
+
+		let [tile] = assert_count!(
+			1,
+			app.world()
+				.iter_entities()
+				.filter(|e| e.get::<TileType>().map(|t| **t) == Some(TileTypeValue::Obstacle))
+		);
+		assert_eq!(
+			Some(&Transform::from_xyz(1., 1., 0.)),
+			tile.get::<Transform>()
+		);
+	}
+}
+
+#[cfg(test)]
+mod test_track_obstacles {
+	use super::*;
+	use crate::{new_handle, test_tools::SingleThreaded};
+
+	fn setup(handle: &Handle<ChunkedGrid>) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut grids = Assets::default();
+
+		grids.insert(
+			handle,
+			ChunkedGrid {
+				chunk_size: 2,
+				..default()
+			},
+		);
+		app.insert_resource(grids);
+		app.add_systems(Update, GridChunks::track_obstacles);
+
+		app
+	}
+
+	#[test]
+	fn store_obstacle() {
+		let handle = new_handle!(ChunkedGrid);
+		let mut app = setup(&handle);
+		let entity = app
+			.world_mut()
+			.spawn((
+				GridChunks {
+					obstacles: ChunkedObstacles::new(2),
+				},
+				GridContext::from_handle(handle),
+			))
+			.with_child((
+				ChunkTile {
+					chunk: ComputeGridNode::new(-1, 0),
+				},
+				TileType::from_value(TileTypeValue::Obstacle),
+				Transform::from_xyz(-1., 0., 0.),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(true),
+			app.world()
+				.entity(entity)
+				.get::<GridChunks>()
+				.map(|chunks| chunks.obstacles.contains(&ComputeGridNode::new(-1, 0)))
+		);
+	}
+
+	#[test]
+	fn remove_obstacle() {
+		let handle = new_handle!(ChunkedGrid);
+		let mut app = setup(&handle);
+		let mut obstacles = ChunkedObstacles::new(2);
+		obstacles.insert(ComputeGridNode::new(-1, 0));
+		let entity = app
+			.world_mut()
+			.spawn((GridChunks { obstacles }, GridContext::from_handle(handle)))
+			.with_child((
+				ChunkTile {
+					chunk: ComputeGridNode::new(-1, 0),
+				},
+				TileType::from_value(TileTypeValue::Walkable),
+				Transform::from_xyz(-1., 0., 0.),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(false),
+			app.world()
+				.entity(entity)
+				.get::<GridChunks>()
+				.map(|chunks| chunks.obstacles.contains(&ComputeGridNode::new(-1, 0)))
+		);
+	}
+}
+
+#[cfg(test)]
+mod test_update_context {
+	use super::*;
+	use crate::{
+		new_handle,
+		test_tools::SingleThreaded,
+		traits::computable_grid::{dense::Obstacles, ComputeGrid},
+	};
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+		app.add_systems(Update, GridChunks::update_context);
+
+		app
+	}
+
+	#[test]
+	fn set_grid_and_obstacles_of_loaded_chunks() {
+		let mut app = setup();
+		let mut obstacles = ChunkedObstacles::new(2);
+		obstacles.load(ComputeGridNode::new(0, 0));
+		obstacles.load(ComputeGridNode::new(1, 0));
+		obstacles.insert(ComputeGridNode::new(2, 1));
+		let entity = app
+			.world_mut()
+			.spawn((
+				GridChunks { obstacles },
+				GridContext::from_handle(new_handle!(ChunkedGrid)),
+			))
+			.id();
+
+		app.update();
+
+		let grid = ComputeGrid {
+			min: ComputeGridNode::new(0, 0),
+			max: ComputeGridNode::new(3, 1),
+		};
+		assert_eq!(
+			Some((
+				grid,
+				&Obstacles::from_nodes(grid, [ComputeGridNode::new(2, 1)])
+			)),
+			app.world()
+				.entity(entity)
+				.get::<GridContext<ChunkedGrid>>()
+				.map(|context| (context.grid, &context.obstacles))
+		);
+	}
+}
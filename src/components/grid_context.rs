@@ -4,10 +4,10 @@ use super::{
 };
 use crate::{
 	assets::grid::Grid,
-	traits::computable_grid::{ComputableGrid, ComputeGrid, ComputeGridNode, GetComputeGridNode},
+	traits::computable_grid::{dense::Obstacles, ComputableGrid, ComputeGrid, GetComputeGridNode},
 };
 use bevy::prelude::*;
-use std::{collections::HashSet, fmt::Debug};
+use std::fmt::Debug;
 
 #[derive(Component, Debug, PartialEq)]
 pub struct GridContext<TGrid = Grid>
@@ -16,7 +16,7 @@ where
 {
 	pub handle: Handle<TGrid>,
 	pub grid: ComputeGrid,
-	pub obstacles: HashSet<ComputeGridNode>,
+	pub obstacles: Obstacles,
 }
 
 impl<TGrid> GridContext<TGrid>
@@ -27,7 +27,7 @@ where
 		Self {
 			handle,
 			grid: ComputeGrid::default(),
-			obstacles: HashSet::default(),
+			obstacles: Obstacles::default(),
 		}
 	}
 
@@ -144,7 +144,7 @@ fn spawn_new_tiles<TGrid>(
 	};
 
 	context.grid = grid.grid();
-	context.obstacles.clear();
+	context.obstacles = Obstacles::new(context.grid);
 	for Vec2 { x, y } in grid.translations() {
 		entity.with_child((Tile, Transform::from_xyz(x, y, 0.)));
 	}
@@ -168,7 +168,13 @@ fn is_contained_in<'a>(tiles: &'a Query<(), With<Tile>>) -> impl FnMut(&&Entity)
 #[cfg(test)]
 mod test_spawning_tiles {
 	use super::*;
-	use crate::{assert_count, components::tile::Tile, new_handle, test_tools::SingleThreaded};
+	use crate::{
+		assert_count,
+		components::tile::Tile,
+		new_handle,
+		test_tools::SingleThreaded,
+		traits::computable_grid::ComputeGridNode,
+	};
 	use std::vec::IntoIter;
 
 	#[derive(Asset, TypePath, Default)]
@@ -293,7 +299,13 @@ mod test_spawning_tiles {
 			.world_mut()
 			.spawn(GridContext {
 				handle: handle.clone(),
-				obstacles: HashSet::from([ComputeGridNode::new(1, 2)]),
+				obstacles: Obstacles::from_nodes(
+					ComputeGrid {
+						min: ComputeGridNode::new(1, 2),
+						max: ComputeGridNode::new(3, 4),
+					},
+					[ComputeGridNode::new(1, 2)],
+				),
 				..default()
 			})
 			.id();
@@ -303,7 +315,10 @@ mod test_spawning_tiles {
 		app.update();
 
 		assert_eq!(
-			Some(&HashSet::from([])),
+			Some(&Obstacles::new(ComputeGrid {
+				min: ComputeGridNode::new(1, 2),
+				max: ComputeGridNode::new(3, 4),
+			})),
 			app.world()
 				.entity(entity)
 				.get::<GridContext<_Grid>>()
@@ -438,6 +453,7 @@ mod test_tracking_of_tiles {
 		components::tile_type::{TileType, TileTypeValue},
 		new_handle,
 		test_tools::SingleThreaded,
+		traits::computable_grid::ComputeGridNode,
 	};
 
 	#[derive(Asset, TypePath)]
@@ -477,16 +493,26 @@ mod test_tracking_of_tiles {
 		app
 	}
 
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(3, 3),
+	};
+
+	fn context(handle: Handle<_Grid>) -> GridContext<_Grid> {
+		GridContext {
+			handle,
+			grid: GRID,
+			obstacles: Obstacles::new(GRID),
+		}
+	}
+
 	#[test]
 	fn add_obstacle() {
 		let handle = new_handle!(_Grid);
 		let mut app = setup(&handle, _Grid);
 		let entity = app
 			.world_mut()
-			.spawn(GridContext {
-				handle,
-				..default()
-			})
+			.spawn(context(handle))
 			.with_child((
 				TileType::from_value(TileTypeValue::Obstacle),
 				Transform::from_xyz(1., 2., 3.),
@@ -496,11 +522,11 @@ mod test_tracking_of_tiles {
 		app.update();
 
 		assert_eq!(
-			Some(&HashSet::from([ComputeGridNode::new(1, 2)])),
+			Some(vec![ComputeGridNode::new(1, 2)]),
 			app.world()
 				.entity(entity)
 				.get::<GridContext<_Grid>>()
-				.map(|g| &g.obstacles)
+				.map(|g| g.obstacles.iter().collect::<Vec<_>>())
 		);
 	}
 
@@ -508,13 +534,7 @@ mod test_tracking_of_tiles {
 	fn do_not_add_obstacle_when_not_child() {
 		let handle = new_handle!(_Grid);
 		let mut app = setup(&handle, _Grid);
-		let entity = app
-			.world_mut()
-			.spawn(GridContext {
-				handle,
-				..default()
-			})
-			.id();
+		let entity = app.world_mut().spawn(context(handle)).id();
 		app.world_mut().spawn((
 			TileType::from_value(TileTypeValue::Obstacle),
 			Transform::from_xyz(1., 2., 3.),
@@ -523,11 +543,11 @@ mod test_tracking_of_tiles {
 		app.update();
 
 		assert_eq!(
-			Some(&HashSet::from([])),
+			Some(vec![]),
 			app.world()
 				.entity(entity)
 				.get::<GridContext<_Grid>>()
-				.map(|g| &g.obstacles)
+				.map(|g| g.obstacles.iter().collect::<Vec<_>>())
 		);
 	}
 
@@ -537,10 +557,7 @@ mod test_tracking_of_tiles {
 		let mut app = setup(&handle, _Grid);
 		let entity = app
 			.world_mut()
-			.spawn(GridContext {
-				handle,
-				..default()
-			})
+			.spawn(context(handle))
 			.with_child((
 				TileType::from_value(TileTypeValue::Walkable),
 				Transform::from_xyz(1., 2., 3.),
@@ -550,11 +567,11 @@ mod test_tracking_of_tiles {
 		app.update();
 
 		assert_eq!(
-			Some(&HashSet::from([])),
+			Some(vec![]),
 			app.world()
 				.entity(entity)
 				.get::<GridContext<_Grid>>()
-				.map(|g| &g.obstacles)
+				.map(|g| g.obstacles.iter().collect::<Vec<_>>())
 		);
 	}
 
@@ -564,10 +581,7 @@ mod test_tracking_of_tiles {
 		let mut app = setup(&handle, _Grid);
 		let entity = app
 			.world_mut()
-			.spawn(GridContext {
-				handle,
-				..default()
-			})
+			.spawn(context(handle))
 			.with_child((
 				TileType::from_value(TileTypeValue::Obstacle),
 				Transform::from_xyz(1., 2., 3.),
@@ -587,13 +601,7 @@ mod test_tracking_of_tiles {
 	fn mut_deref_context_if_tile_type_mutable_deref_occurred() {
 		let handle = new_handle!(_Grid);
 		let mut app = setup(&handle, _Grid);
-		let entity = app
-			.world_mut()
-			.spawn(GridContext {
-				handle,
-				..default()
-			})
-			.id();
+		let entity = app.world_mut().spawn(context(handle)).id();
 		let child = app
 			.world_mut()
 			.spawn((
@@ -623,9 +631,8 @@ mod test_tracking_of_tiles {
 		let entity = app
 			.world_mut()
 			.spawn(GridContext {
-				handle,
-				obstacles: HashSet::from([ComputeGridNode::new(1, 2)]),
-				..default()
+				obstacles: Obstacles::from_nodes(GRID, [ComputeGridNode::new(1, 2)]),
+				..context(handle)
 			})
 			.with_child((
 				TileType::from_value(TileTypeValue::Walkable),
@@ -636,11 +643,11 @@ mod test_tracking_of_tiles {
 		app.update();
 
 		assert_eq!(
-			Some(&HashSet::from([])),
+			Some(vec![]),
 			app.world()
 				.entity(entity)
 				.get::<GridContext<_Grid>>()
-				.map(|g| &g.obstacles)
+				.map(|g| g.obstacles.iter().collect::<Vec<_>>())
 		);
 	}
 }
@@ -0,0 +1,516 @@
+use super::{
+	compute_path_method::{
+		conflict_based_search::{conflicts, ConflictBasedSearch, PlanError},
+		PathMarkers,
+	},
+	computed_path::ComputedPath,
+	grid_context::GridContext,
+	tile_type::TileType,
+	use_asset::UseAsset,
+};
+use crate::traits::{
+	computable_grid::{ComputeGridNode, GetComputeGridNode, GetTranslation},
+	compute_path::NewComputer,
+};
+use bevy::{
+	prelude::*,
+	tasks::{block_on, poll_once, AsyncComputeTaskPool, Task},
+};
+use std::path::Path;
+
+/// Conflict free plans for several agents, computed with [`ConflictBasedSearch`].
+///
+/// Agents are formed by agent start and agent goal tiles, paired in placement order.
+/// `conflicts` holds the positions, where the agents would have collided, if each
+/// of them had been planned on its own.
+#[derive(Component, Debug, PartialEq, Default)]
+#[require(Transform, Visibility)]
+pub struct MultiAgentPlan {
+	pub paths: Vec<Vec<Vec3>>,
+	pub conflicts: Vec<Vec3>,
+	pub error: Option<PlanError>,
+}
+
+impl MultiAgentPlan {
+	pub fn compute<TGrid>(
+		mut commands: Commands,
+		grids: Res<Assets<TGrid>>,
+		contexts: Query<(Entity, &GridContext<TGrid>)>,
+		plans: Query<(Entity, &Parent), Plans>,
+		tiles: Query<(Entity, &Transform, Ref<TileType>)>,
+		mut removed_tiles: RemovedComponents<TileType>,
+		mut markers: Local<PathMarkers>,
+	) where
+		TGrid: Asset + GetComputeGridNode + GetTranslation,
+	{
+		let removed = removed_tiles.read().collect::<Vec<_>>();
+		if !tiles.iter().any(|(.., tile_type)| tile_type.is_changed()) && removed.is_empty() {
+			return;
+		}
+
+		markers.update(tiles, removed);
+		let agents = markers.agents();
+
+		for (entity, context) in &contexts {
+			despawn_plans(&mut commands, entity, &plans);
+
+			if agents.len() < 2 {
+				continue;
+			}
+			let Some(grid) = grids.get(&context.handle) else {
+				continue;
+			};
+			let Some(pending) = PendingPlan::spawn(grid, context, &agents) else {
+				continue;
+			};
+			let Some(mut entity) = commands.get_entity(entity) else {
+				continue;
+			};
+
+			entity.with_child(pending);
+		}
+	}
+
+	/// Replaces finished [`PendingPlan`]s with their plan.
+	pub fn poll<TGrid>(
+		mut commands: Commands,
+		grids: Res<Assets<TGrid>>,
+		contexts: Query<&GridContext<TGrid>>,
+		mut pending: Query<(Entity, &Parent, &mut PendingPlan)>,
+	) where
+		TGrid: Asset + GetTranslation,
+	{
+		for (entity, parent, mut pending) in &mut pending {
+			let Some(computation) = block_on(poll_once(&mut pending.task)) else {
+				continue;
+			};
+			let Some(mut entity) = commands.get_entity(entity) else {
+				continue;
+			};
+			let Some(grid) = contexts
+				.get(parent.get())
+				.ok()
+				.and_then(|context| grids.get(&context.handle))
+			else {
+				entity.despawn_recursive();
+				continue;
+			};
+
+			entity
+				.remove::<PendingPlan>()
+				.try_insert(Self::translated(grid, computation));
+		}
+	}
+
+	fn translated<TGrid>(grid: &TGrid, computation: PlanComputation) -> Self
+	where
+		TGrid: GetTranslation,
+	{
+		let translate = |node: &ComputeGridNode| grid.translation(*node).map(|t| t.extend(1.));
+		let (paths, error) = match computation.paths {
+			Ok(paths) => (paths, None),
+			Err(error) => {
+				warn!("{error}");
+				(vec![], Some(error))
+			}
+		};
+
+		Self {
+			paths: paths
+				.iter()
+				.map(|path| path.iter().filter_map(translate).collect())
+				.collect(),
+			conflicts: computation.conflicts.iter().filter_map(translate).collect(),
+			error,
+		}
+	}
+
+	pub fn draw(mut commands: Commands, plans: Query<(Entity, &Self), Changed<Self>>) {
+		for (entity, plan) in &plans {
+			let Some(mut entity) = commands.get_entity(entity) else {
+				continue;
+			};
+
+			entity.despawn_descendants();
+			entity.with_children(|parent| {
+				for path in &plan.paths {
+					let mut path = path.clone();
+					path.dedup();
+					parent.spawn(ComputedPath {
+						path,
+						draw_connections: true,
+					});
+				}
+
+				for conflict in &plan.conflicts {
+					parent.spawn((ConflictMarker, Transform::from_translation(*conflict)));
+				}
+			});
+		}
+	}
+}
+
+/// Conflict based search running on the [`AsyncComputeTaskPool`]. Despawning the entity
+/// cancels the search.
+#[derive(Component)]
+pub struct PendingPlan {
+	task: Task<PlanComputation>,
+}
+
+impl PendingPlan {
+	fn spawn<TGrid>(
+		grid: &TGrid,
+		context: &GridContext<TGrid>,
+		agents: &[(Vec2, Vec2)],
+	) -> Option<Self>
+	where
+		TGrid: Asset + GetComputeGridNode,
+	{
+		let agents = agents
+			.iter()
+			.map(|(start, end)| {
+				Some((
+					grid.compute_grid_node(*start)?,
+					grid.compute_grid_node(*end)?,
+				))
+			})
+			.collect::<Option<Vec<_>>>()?;
+		let search = ConflictBasedSearch::new(context.grid, context.obstacles.clone());
+
+		Some(Self {
+			task: AsyncComputeTaskPool::get().spawn(async move {
+				let mut conflicts = conflicts(&search.independent_plan(&agents))
+					.iter()
+					.map(|conflict| conflict.node())
+					.collect::<Vec<_>>();
+
+				conflicts.sort();
+				conflicts.dedup();
+
+				PlanComputation {
+					paths: search.plan(&agents),
+					conflicts,
+				}
+			}),
+		})
+	}
+}
+
+struct PlanComputation {
+	paths: Result<Vec<Vec<ComputeGridNode>>, PlanError>,
+	conflicts: Vec<ComputeGridNode>,
+}
+
+type Plans = Or<(With<MultiAgentPlan>, With<PendingPlan>)>;
+
+fn despawn_plans(commands: &mut Commands, entity: Entity, plans: &Query<(Entity, &Parent), Plans>) {
+	for (plan, parent) in plans {
+		if parent.get() != entity {
+			continue;
+		}
+		let Some(plan) = commands.get_entity(plan) else {
+			continue;
+		};
+		plan.despawn_recursive();
+	}
+}
+
+#[derive(Component, Debug, PartialEq)]
+#[require(
+	Transform,
+	Visibility,
+	UseAsset<Mesh>(Self::asset),
+	UseAsset<ColorMaterial>(Self::asset)
+)]
+pub struct ConflictMarker;
+
+impl ConflictMarker {
+	fn asset<TAsset>() -> UseAsset<TAsset>
+	where
+		TAsset: Asset,
+	{
+		UseAsset::new(Path::new("conflict_marker.json"))
+	}
+}
+
+#[cfg(test)]
+mod test_compute {
+	use super::*;
+	use crate::{
+		assert_count,
+		components::tile_type::TileTypeValue,
+		new_handle,
+		test_tools::SingleThreaded,
+		traits::computable_grid::{dense::Obstacles, ComputeGrid},
+	};
+	use bevy::tasks::TaskPool;
+
+	#[derive(Asset, TypePath)]
+	struct _Grid;
+
+	impl GetComputeGridNode for _Grid {
+		fn compute_grid_node(&self, Vec2 { x, y }: Vec2) -> Option<ComputeGridNode> {
+			Some(ComputeGridNode::new(x as i32, y as i32))
+		}
+	}
+
+	impl GetTranslation for _Grid {
+		fn translation(&self, ComputeGridNode { x, y }: ComputeGridNode) -> Option<Vec2> {
+			Some(Vec2::new(x as f32, y as f32))
+		}
+	}
+
+	fn setup(handle: &Handle<_Grid>) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut grids = Assets::default();
+
+		grids.insert(handle, _Grid);
+		AsyncComputeTaskPool::get_or_init(TaskPool::default);
+		app.insert_resource(grids);
+		app.add_systems(
+			Update,
+			(
+				MultiAgentPlan::compute::<_Grid>,
+				MultiAgentPlan::poll::<_Grid>,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	/// Updates until no plan is pending anymore.
+	fn update(app: &mut App) {
+		app.update();
+		while app
+			.world()
+			.iter_entities()
+			.any(|entity| is::<PendingPlan>(&entity))
+		{
+			app.update();
+		}
+	}
+
+	fn context(handle: Handle<_Grid>) -> GridContext<_Grid> {
+		let grid = ComputeGrid {
+			min: ComputeGridNode::new(0, 0),
+			max: ComputeGridNode::new(2, 2),
+		};
+
+		GridContext {
+			handle,
+			grid,
+			obstacles: Obstacles::new(grid),
+		}
+	}
+
+	fn is<TComponent>(entity: &EntityRef) -> bool
+	where
+		TComponent: Component,
+	{
+		entity.contains::<TComponent>()
+	}
+
+	fn agent_start(x: f32, y: f32) -> (TileType, Transform) {
+		(
+			TileType::from_value(TileTypeValue::AgentStart),
+			Transform::from_xyz(x, y, 0.),
+		)
+	}
+
+	fn agent_goal(x: f32, y: f32) -> (TileType, Transform) {
+		(
+			TileType::from_value(TileTypeValue::AgentGoal),
+			Transform::from_xyz(x, y, 0.),
+		)
+	}
+
+	#[test]
+	fn plan_agents_from_agent_markers_in_placement_order() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn(context(handle))
+			.with_child(agent_start(0., 1.))
+			.with_child(agent_goal(2., 1.))
+			.with_child(agent_start(1., 0.))
+			.with_child(agent_goal(1., 2.));
+
+		update(&mut app);
+
+		let [plan] = assert_count!(1, app.world().iter_entities().filter(is::<MultiAgentPlan>));
+		let plan = plan.get::<MultiAgentPlan>().unwrap();
+		assert_eq!(
+			(
+				2,
+				vec![Vec3::new(1., 1., 1.)],
+				Some(&Vec3::new(2., 1., 1.)),
+				Some(&Vec3::new(1., 2., 1.))
+			),
+			(
+				plan.paths.len(),
+				plan.conflicts.clone(),
+				plan.paths[0].last(),
+				plan.paths[1].last()
+			)
+		);
+	}
+
+	#[test]
+	fn no_plan_for_single_agent() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn(context(handle))
+			.with_child(agent_start(0., 1.))
+			.with_child(agent_goal(2., 1.));
+
+		update(&mut app);
+
+		assert_count!(0, app.world().iter_entities().filter(is::<MultiAgentPlan>));
+	}
+
+	#[test]
+	fn no_agents_from_start_end_and_waypoints() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn(context(handle))
+			.with_child((
+				TileType::from_value(TileTypeValue::Start),
+				Transform::from_xyz(0., 1., 0.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::End),
+				Transform::from_xyz(2., 1., 0.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Waypoint),
+				Transform::from_xyz(1., 0., 0.),
+			))
+			.with_child((
+				TileType::from_value(TileTypeValue::Waypoint),
+				Transform::from_xyz(1., 2., 0.),
+			));
+
+		update(&mut app);
+
+		assert_count!(0, app.world().iter_entities().filter(is::<MultiAgentPlan>));
+	}
+
+	#[test]
+	fn report_agents_sharing_start() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		app.world_mut()
+			.spawn(context(handle))
+			.with_child(agent_start(0., 1.))
+			.with_child(agent_goal(2., 1.))
+			.with_child(agent_start(0., 1.))
+			.with_child(agent_goal(1., 2.));
+
+		update(&mut app);
+
+		let [plan] = assert_count!(1, app.world().iter_entities().filter(is::<MultiAgentPlan>));
+		assert_eq!(
+			Some(&MultiAgentPlan {
+				paths: vec![],
+				conflicts: vec![Vec3::new(0., 1., 1.)],
+				error: Some(PlanError::SharedStart {
+					agents: [0, 1],
+					node: ComputeGridNode::new(0, 1),
+				}),
+			}),
+			plan.get::<MultiAgentPlan>()
+		);
+	}
+
+	#[test]
+	fn replace_plan_when_markers_change() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle);
+		let entity = app
+			.world_mut()
+			.spawn(context(handle))
+			.with_child(agent_start(0., 1.))
+			.with_child(agent_goal(2., 1.))
+			.with_child(agent_start(1., 0.))
+			.id();
+
+		update(&mut app);
+		app.world_mut().spawn(agent_goal(1., 2.)).set_parent(entity);
+		update(&mut app);
+		app.world_mut()
+			.spawn((
+				TileType::from_value(TileTypeValue::Walkable),
+				Transform::from_xyz(0., 0., 0.),
+			))
+			.set_parent(entity);
+		update(&mut app);
+
+		assert_count!(1, app.world().iter_entities().filter(is::<MultiAgentPlan>));
+	}
+}
+
+#[cfg(test)]
+mod test_draw {
+	use super::*;
+	use crate::{assert_count, test_tools::SingleThreaded};
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+		app.add_systems(Update, MultiAgentPlan::draw);
+
+		app
+	}
+
+	fn is<TComponent>(entity: &EntityRef) -> bool
+	where
+		TComponent: Component,
+	{
+		entity.contains::<TComponent>()
+	}
+
+	#[test]
+	fn spawn_paths_without_waiting_nodes() {
+		let mut app = setup();
+		app.world_mut().spawn(MultiAgentPlan {
+			paths: vec![vec![
+				Vec3::new(1., 1., 1.),
+				Vec3::new(1., 1., 1.),
+				Vec3::new(2., 1., 1.),
+			]],
+			conflicts: vec![],
+			error: None,
+		});
+
+		app.update();
+
+		let [path] = assert_count!(1, app.world().iter_entities().filter(is::<ComputedPath>));
+		assert_eq!(
+			Some(&ComputedPath {
+				path: vec![Vec3::new(1., 1., 1.), Vec3::new(2., 1., 1.)],
+				draw_connections: true,
+			}),
+			path.get::<ComputedPath>()
+		);
+	}
+
+	#[test]
+	fn spawn_conflict_markers() {
+		let mut app = setup();
+		app.world_mut().spawn(MultiAgentPlan {
+			paths: vec![],
+			conflicts: vec![Vec3::new(1., 2., 1.)],
+			error: None,
+		});
+
+		app.update();
+
+		let [marker] = assert_count!(1, app.world().iter_entities().filter(is::<ConflictMarker>));
+		assert_eq!(
+			Some(&Transform::from_xyz(1., 2., 1.)),
+			marker.get::<Transform>()
+		);
+	}
+}
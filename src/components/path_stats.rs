@@ -0,0 +1,327 @@
+use super::{computed_path::ComputedPath, despawn::Despawn};
+use crate::traits::computable_grid::ComputeGridNode;
+use bevy::{prelude::*, utils::Duration};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[derive(Component, Debug, PartialEq, Clone)]
+pub struct PathStats {
+	pub method: String,
+	pub length: f32,
+	/// diagonal steps count as one
+	pub steps: u32,
+	pub nodes: usize,
+	pub turns: usize,
+	pub duration: Duration,
+	pub expanded: Option<usize>,
+	pub bound: Option<f32>,
+}
+
+impl PathStats {
+	pub fn new<TMethod>(
+		nodes: &[ComputeGridNode],
+		path: &[Vec2],
+		expanded: Option<usize>,
+		duration: Duration,
+		bound: Option<f32>,
+	) -> Self {
+		let deltas = nodes
+			.windows(2)
+			.filter_map(|pair| match pair {
+				[a, b] => Some(*b - *a),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		Self {
+			method: Self::short_name::<TMethod>(),
+			length: path.windows(2).map(|pair| pair[0].distance(pair[1])).sum(),
+			steps: deltas
+				.iter()
+				.map(|delta| delta.x.unsigned_abs().max(delta.y.unsigned_abs()))
+				.sum(),
+			nodes: nodes.len(),
+			turns: deltas
+				.windows(2)
+				.filter(|pair| pair[0].x * pair[1].y != pair[0].y * pair[1].x)
+				.count(),
+			duration,
+			expanded,
+			bound,
+		}
+	}
+
+	fn short_name<T>() -> String {
+		std::any::type_name::<T>()
+			.split_inclusive(['<', '>', ',', ' '])
+			.map(|part| part.rsplit("::").next().unwrap_or(part))
+			.collect()
+	}
+}
+
+impl Display for PathStats {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		writeln!(f, "Method: {}", self.method)?;
+		writeln!(f, "Length: {:.1} ({} steps)", self.length, self.steps)?;
+		writeln!(f, "Nodes: {}", self.nodes)?;
+		writeln!(f, "Turns: {}", self.turns)?;
+		writeln!(f, "Time: {:.3} ms", self.duration.as_secs_f64() * 1000.)?;
+		match self.expanded {
+			Some(expanded) => writeln!(f, "Expanded: {expanded}")?,
+			None => writeln!(f, "Expanded: -")?,
+		}
+		match self.bound {
+			Some(bound) => write!(f, "Bound: {bound:.2}"),
+			None => write!(f, "Bound: -"),
+		}
+	}
+}
+
+#[derive(Component, Debug, PartialEq, Default)]
+#[require(Text, Node(Self::node))]
+pub struct PathStatsPanel;
+
+impl PathStatsPanel {
+	const MARGIN: Val = Val::Px(10.);
+
+	fn node() -> Node {
+		Node {
+			position_type: PositionType::Absolute,
+			top: Self::MARGIN,
+			left: Self::MARGIN,
+			..default()
+		}
+	}
+
+	/// Falls back to a remaining path, when paths are removed.
+	pub fn update(
+		mut panels: Query<&mut Text, With<Self>>,
+		changed: Query<&PathStats, Changed<ComputedPath>>,
+		remaining: Query<&PathStats, (With<ComputedPath>, Without<Despawn>)>,
+		mut removed: RemovedComponents<ComputedPath>,
+	) {
+		let removed = removed.read().count() > 0;
+		let stats = match changed.iter().last() {
+			Some(stats) => Some(stats),
+			None if removed => remaining.iter().last(),
+			None => return,
+		};
+		let stats = stats.map(PathStats::to_string).unwrap_or_default();
+
+		for mut text in &mut panels {
+			*text = Text::new(stats.clone());
+		}
+	}
+}
+
+#[cfg(test)]
+mod test_stats {
+	use super::*;
+
+	struct _Method;
+
+	fn nodes(nodes: &[(i32, i32)]) -> Vec<ComputeGridNode> {
+		nodes
+			.iter()
+			.map(|(x, y)| ComputeGridNode::new(*x, *y))
+			.collect()
+	}
+
+	#[test]
+	fn method_name() {
+		let stats = PathStats::new::<_Method>(&[], &[], None, Duration::ZERO, None);
+
+		assert_eq!("_Method", stats.method);
+	}
+
+	#[test]
+	fn method_name_with_generics() {
+		struct _Generic<T>(T);
+
+		let stats = PathStats::new::<_Generic<_Method>>(&[], &[], None, Duration::ZERO, None);
+
+		assert_eq!("_Generic<_Method>", stats.method);
+	}
+
+	#[test]
+	fn length_in_world_units() {
+		let stats = PathStats::new::<_Method>(
+			&[],
+			&[Vec2::new(0., 0.), Vec2::new(3., 4.), Vec2::new(3., 6.)],
+			None,
+			Duration::ZERO,
+			None,
+		);
+
+		assert_eq!(7., stats.length);
+	}
+
+	#[test]
+	fn grid_steps_and_nodes() {
+		let stats = PathStats::new::<_Method>(
+			&nodes(&[(0, 0), (1, 1), (4, 2)]),
+			&[],
+			None,
+			Duration::ZERO,
+			None,
+		);
+
+		assert_eq!((4, 3), (stats.steps, stats.nodes));
+	}
+
+	#[test]
+	fn turns() {
+		let stats = PathStats::new::<_Method>(
+			&nodes(&[(0, 0), (1, 0), (2, 0), (3, 1), (4, 2), (4, 3)]),
+			&[],
+			None,
+			Duration::ZERO,
+			None,
+		);
+
+		assert_eq!(2, stats.turns);
+	}
+
+	#[test]
+	fn display() {
+		let stats = PathStats {
+			method: "AStar".to_owned(),
+			length: 12.34,
+			steps: 5,
+			nodes: 6,
+			turns: 1,
+			duration: Duration::from_micros(1500),
+			expanded: Some(42),
+			bound: Some(1.5),
+		};
+
+		assert_eq!(
+			"Method: AStar\nLength: 12.3 (5 steps)\nNodes: 6\nTurns: 1\nTime: 1.500 ms\nExpanded: 42\nBound: 1.50",
+			stats.to_string()
+		);
+	}
+
+	#[test]
+	fn bound() {
+		let stats = PathStats::new::<_Method>(&[], &[], None, Duration::ZERO, Some(2.));
+
+		assert_eq!(Some(2.), stats.bound);
+	}
+}
+
+#[cfg(test)]
+mod test_panel {
+	use super::*;
+	use crate::test_tools::SingleThreaded;
+
+	fn stats(nodes: usize) -> PathStats {
+		PathStats {
+			method: "AStar".to_owned(),
+			length: 0.,
+			steps: 0,
+			nodes,
+			turns: 0,
+			duration: Duration::ZERO,
+			expanded: None,
+			bound: None,
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+		app.add_systems(Update, PathStatsPanel::update);
+
+		app
+	}
+
+	#[test]
+	fn show_stats_of_new_path() {
+		let mut app = setup();
+		let panel = app.world_mut().spawn(PathStatsPanel).id();
+		app.world_mut().spawn((ComputedPath::default(), stats(3)));
+
+		app.update();
+
+		assert_eq!(
+			Some(stats(3).to_string().as_str()),
+			app.world().entity(panel).get::<Text>().map(|t| t.as_str())
+		);
+	}
+
+	#[test]
+	fn show_stats_of_changed_path() {
+		let mut app = setup();
+		let panel = app.world_mut().spawn(PathStatsPanel).id();
+		let path = app
+			.world_mut()
+			.spawn((ComputedPath::default(), stats(3)))
+			.id();
+
+		app.update();
+		let mut path = app.world_mut().entity_mut(path);
+		path.insert(stats(4));
+		path.get_mut::<ComputedPath>().unwrap().path = vec![Vec3::ONE];
+		app.update();
+
+		assert_eq!(
+			Some(stats(4).to_string().as_str()),
+			app.world().entity(panel).get::<Text>().map(|t| t.as_str())
+		);
+	}
+
+	#[test]
+	fn keep_stats_while_path_unchanged() {
+		let mut app = setup();
+		let panel = app.world_mut().spawn(PathStatsPanel).id();
+		app.world_mut().spawn((ComputedPath::default(), stats(3)));
+
+		app.update();
+		app.world_mut().entity_mut(panel).insert(Text::new("other"));
+		app.update();
+
+		assert_eq!(
+			Some("other"),
+			app.world().entity(panel).get::<Text>().map(|t| t.as_str())
+		);
+	}
+
+	#[test]
+	fn reset_stats_when_path_removed() {
+		let mut app = setup();
+		let panel = app.world_mut().spawn(PathStatsPanel).id();
+		let path = app
+			.world_mut()
+			.spawn((ComputedPath::default(), stats(3)))
+			.id();
+
+		app.update();
+		app.world_mut().despawn(path);
+		app.update();
+
+		assert_eq!(
+			Some(""),
+			app.world().entity(panel).get::<Text>().map(|t| t.as_str())
+		);
+	}
+
+	#[test]
+	fn keep_stats_of_new_path_when_old_path_removed() {
+		let mut app = setup();
+		let panel = app.world_mut().spawn(PathStatsPanel).id();
+		let old = app
+			.world_mut()
+			.spawn((ComputedPath::default(), stats(3)))
+			.id();
+
+		app.update();
+		app.world_mut().entity_mut(old).insert(Despawn::NextFrame);
+		app.world_mut().spawn((ComputedPath::default(), stats(4)));
+		app.update();
+		app.world_mut().despawn(old);
+		app.update();
+
+		assert_eq!(
+			Some(stats(4).to_string().as_str()),
+			app.world().entity(panel).get::<Text>().map(|t| t.as_str())
+		);
+	}
+}
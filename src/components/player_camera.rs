@@ -1,6 +1,16 @@
-use crate::traits::get_mouse_ray::MouseRayCaster;
-use bevy::prelude::*;
+use super::grid_context::GridContext;
+use crate::traits::{
+	computable_grid::{ComputeGrid, ComputeGridNode, GetTranslation},
+	get_mouse_ray::MouseRayCaster,
+};
+use bevy::{
+	input::mouse::{MouseScrollUnit, MouseWheel},
+	prelude::*,
+};
 
+/// A [`Camera2d`] that pans while dragging the left mouse button with
+/// [`PlayerCamera::PAN_MODIFIER`] held, zooms around the cursor with the scroll wheel
+/// and fits all grids into the window on [`PlayerCamera::FIT_KEY`].
 #[derive(Component, Debug, PartialEq, Default)]
 #[require(Camera2d)]
 pub struct PlayerCamera;
@@ -8,3 +18,396 @@ pub struct PlayerCamera;
 impl MouseRayCaster for PlayerCamera {
 	type TMouseRayCaster = Camera;
 }
+
+impl PlayerCamera {
+	pub const PAN_MODIFIER: KeyCode = KeyCode::Space;
+	pub const PAN_BUTTON: MouseButton = MouseButton::Left;
+	pub const FIT_KEY: KeyCode = KeyCode::KeyF;
+	const ZOOM_PER_LINE: f32 = 1.1;
+	const PIXELS_PER_LINE: f32 = 100.;
+	const MIN_SCALE: f32 = 0.05;
+	const MAX_SCALE: f32 = 20.;
+	const FIT_MARGIN: f32 = 1.1;
+
+	/// Run condition, that is true while panning, so that clicks on tiles can be
+	/// ignored meanwhile.
+	pub fn is_panning(keys: Res<ButtonInput<KeyCode>>) -> bool {
+		keys.pressed(Self::PAN_MODIFIER)
+	}
+
+	pub fn pan(
+		mut cameras: Query<(&mut Transform, &OrthographicProjection), With<Self>>,
+		keys: Res<ButtonInput<KeyCode>>,
+		buttons: Res<ButtonInput<MouseButton>>,
+		mut cursor_moved: EventReader<CursorMoved>,
+	) {
+		let delta = cursor_moved
+			.read()
+			.filter_map(|CursorMoved { delta, .. }| *delta)
+			.sum::<Vec2>();
+
+		if delta == Vec2::ZERO || !keys.pressed(Self::PAN_MODIFIER) {
+			return;
+		}
+		if !buttons.pressed(Self::PAN_BUTTON) {
+			return;
+		}
+
+		for (mut transform, projection) in &mut cameras {
+			// cursor y grows downwards, world y upwards
+			transform.translation.x -= delta.x * projection.scale;
+			transform.translation.y += delta.y * projection.scale;
+		}
+	}
+
+	/// Zooms, such that the world position under the cursor stays under the cursor.
+	pub fn zoom(
+		mut cameras: Query<(&mut Transform, &mut OrthographicProjection), With<Self>>,
+		windows: Query<&Window>,
+		mut mouse_wheel: EventReader<MouseWheel>,
+	) {
+		let lines = mouse_wheel
+			.read()
+			.map(|MouseWheel { unit, y, .. }| match unit {
+				MouseScrollUnit::Line => *y,
+				MouseScrollUnit::Pixel => *y / Self::PIXELS_PER_LINE,
+			})
+			.sum::<f32>();
+
+		if lines == 0. {
+			return;
+		}
+
+		let Ok(window) = windows.get_single() else {
+			return;
+		};
+		let cursor = window
+			.cursor_position()
+			.map(|cursor| cursor_offset(window, cursor))
+			.unwrap_or_default();
+
+		for (mut transform, mut projection) in &mut cameras {
+			let scale = (projection.scale * Self::ZOOM_PER_LINE.powf(-lines))
+				.clamp(Self::MIN_SCALE, Self::MAX_SCALE);
+			let under_cursor = transform.translation.xy() + cursor * projection.scale;
+			let translation = under_cursor - cursor * scale;
+
+			transform.translation.x = translation.x;
+			transform.translation.y = translation.y;
+			projection.scale = scale;
+		}
+	}
+
+	pub fn fit_to_grid<TGrid>(
+		mut cameras: Query<(&mut Transform, &mut OrthographicProjection), With<Self>>,
+		windows: Query<&Window>,
+		keys: Res<ButtonInput<KeyCode>>,
+		contexts: Query<&GridContext<TGrid>>,
+		grids: Res<Assets<TGrid>>,
+	) where
+		TGrid: Asset + GetTranslation,
+	{
+		if !keys.just_pressed(Self::FIT_KEY) {
+			return;
+		}
+
+		let Ok(window) = windows.get_single() else {
+			return;
+		};
+		let Some(bounds) = contexts
+			.iter()
+			.filter_map(|context| Some((grids.get(&context.handle)?, context.grid)))
+			.filter_map(|(grid, compute_grid)| bounds_of(grid, compute_grid))
+			.reduce(|a, b| a.union(b))
+		else {
+			return;
+		};
+		let size = bounds.size() / Vec2::new(window.width(), window.height());
+		let scale = (size.max_element() * Self::FIT_MARGIN).clamp(Self::MIN_SCALE, Self::MAX_SCALE);
+		let center = bounds.center();
+
+		for (mut transform, mut projection) in &mut cameras {
+			transform.translation.x = center.x;
+			transform.translation.y = center.y;
+			projection.scale = scale;
+		}
+	}
+}
+
+/// Cursor position relative to the window center, with y pointing upwards.
+fn cursor_offset(window: &Window, cursor: Vec2) -> Vec2 {
+	let offset = cursor - Vec2::new(window.width(), window.height()) / 2.;
+
+	Vec2::new(offset.x, -offset.y)
+}
+
+/// Bounds of all tiles, assuming tile translations grow with their nodes.
+fn bounds_of<TGrid>(grid: &TGrid, ComputeGrid { min, max }: ComputeGrid) -> Option<Rect>
+where
+	TGrid: GetTranslation,
+{
+	let min_translation = grid.translation(min)?;
+	let max_translation = grid.translation(max)?;
+	let next_translation = grid.translation(min + ComputeGridNode::new(1, 1))?;
+	let half_tile = (next_translation - min_translation).abs() / 2.;
+
+	Some(Rect::from_corners(
+		min_translation - half_tile,
+		max_translation + half_tile,
+	))
+}
+
+#[cfg(test)]
+mod test_pan {
+	use super::*;
+	use crate::test_tools::SingleThreaded;
+
+	fn setup(keys: &[KeyCode], buttons: &[MouseButton]) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut key_input = ButtonInput::<KeyCode>::default();
+		let mut button_input = ButtonInput::<MouseButton>::default();
+
+		for key in keys {
+			key_input.press(*key);
+		}
+		for button in buttons {
+			button_input.press(*button);
+		}
+		app.insert_resource(key_input);
+		app.insert_resource(button_input);
+		app.add_event::<CursorMoved>();
+		app.add_systems(Update, PlayerCamera::pan);
+
+		app
+	}
+
+	fn cursor_moved(delta: Vec2) -> CursorMoved {
+		CursorMoved {
+			window: Entity::PLACEHOLDER,
+			position: Vec2::ZERO,
+			delta: Some(delta),
+		}
+	}
+
+	fn camera(scale: f32) -> impl Bundle {
+		(
+			PlayerCamera,
+			OrthographicProjection {
+				scale,
+				..OrthographicProjection::default_2d()
+			},
+		)
+	}
+
+	#[test]
+	fn move_against_cursor_scaled_by_zoom() {
+		let mut app = setup(&[KeyCode::Space], &[MouseButton::Left]);
+		let camera = app.world_mut().spawn(camera(2.)).id();
+		app.world_mut().send_event(cursor_moved(Vec2::new(3., 4.)));
+		app.world_mut().send_event(cursor_moved(Vec2::new(1., 1.)));
+
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::from_xyz(-8., 10., 0.)),
+			app.world().entity(camera).get::<Transform>()
+		);
+	}
+
+	#[test]
+	fn do_not_move_without_pan_modifier() {
+		let mut app = setup(&[], &[MouseButton::Left]);
+		let camera = app.world_mut().spawn(camera(1.)).id();
+		app.world_mut().send_event(cursor_moved(Vec2::new(3., 4.)));
+
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::default()),
+			app.world().entity(camera).get::<Transform>()
+		);
+	}
+
+	#[test]
+	fn do_not_move_without_pan_button() {
+		let mut app = setup(&[KeyCode::Space], &[]);
+		let camera = app.world_mut().spawn(camera(1.)).id();
+		app.world_mut().send_event(cursor_moved(Vec2::new(3., 4.)));
+
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::default()),
+			app.world().entity(camera).get::<Transform>()
+		);
+	}
+}
+
+#[cfg(test)]
+mod test_zoom {
+	use super::*;
+	use crate::test_tools::{primary_window, SingleThreaded};
+
+	fn setup(cursor: Vec2) -> App {
+		let mut app = App::new().single_threaded(Update);
+		app.add_event::<MouseWheel>();
+		app.add_systems(Update, PlayerCamera::zoom);
+		app.world_mut().spawn(primary_window(cursor));
+
+		app
+	}
+
+	fn scroll(y: f32) -> MouseWheel {
+		MouseWheel {
+			unit: MouseScrollUnit::Line,
+			x: 0.,
+			y,
+			window: Entity::PLACEHOLDER,
+		}
+	}
+
+	fn world_under_cursor(app: &App, camera: Entity, cursor: Vec2) -> Option<Vec2> {
+		let entity = app.world().entity(camera);
+		let transform = entity.get::<Transform>()?;
+		let projection = entity.get::<OrthographicProjection>()?;
+		let offset = cursor - Vec2::new(400., 300.);
+
+		Some(transform.translation.xy() + Vec2::new(offset.x, -offset.y) * projection.scale)
+	}
+
+	#[test]
+	fn zoom_in_when_scrolling_up() {
+		let mut app = setup(Vec2::new(400., 300.));
+		let camera = app.world_mut().spawn(PlayerCamera).id();
+		app.world_mut().send_event(scroll(2.));
+
+		app.update();
+
+		assert_eq!(
+			Some(1. / 1.1 / 1.1),
+			app.world()
+				.entity(camera)
+				.get::<OrthographicProjection>()
+				.map(|projection| projection.scale)
+		);
+	}
+
+	#[test]
+	fn keep_world_position_under_cursor() {
+		let cursor = Vec2::new(600., 100.);
+		let mut app = setup(cursor);
+		let camera = app
+			.world_mut()
+			.spawn((PlayerCamera, Transform::from_xyz(30., -20., 0.)))
+			.id();
+		let before = world_under_cursor(&app, camera, cursor);
+		app.world_mut().send_event(scroll(-3.));
+
+		app.update();
+
+		let after = world_under_cursor(&app, camera, cursor);
+		assert_eq!(
+			Some(true),
+			before
+				.zip(after)
+				.map(|(before, after)| before.abs_diff_eq(after, 0.001)),
+			"{before:?} != {after:?}"
+		);
+	}
+
+	#[test]
+	fn clamp_scale() {
+		let mut app = setup(Vec2::new(400., 300.));
+		let camera = app.world_mut().spawn(PlayerCamera).id();
+		app.world_mut().send_event(scroll(-1000.));
+
+		app.update();
+
+		assert_eq!(
+			Some(PlayerCamera::MAX_SCALE),
+			app.world()
+				.entity(camera)
+				.get::<OrthographicProjection>()
+				.map(|projection| projection.scale)
+		);
+	}
+}
+
+#[cfg(test)]
+mod test_fit_to_grid {
+	use super::*;
+	use crate::{
+		new_handle,
+		test_tools::{primary_window, SingleThreaded},
+	};
+
+	#[derive(Asset, TypePath)]
+	struct _Grid;
+
+	impl GetTranslation for _Grid {
+		fn translation(&self, ComputeGridNode { x, y }: ComputeGridNode) -> Option<Vec2> {
+			Some(Vec2::new(x as f32, y as f32) * 10.)
+		}
+	}
+
+	fn setup(handle: &Handle<_Grid>, pressed: bool) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut grids = Assets::default();
+		let mut keys = ButtonInput::<KeyCode>::default();
+
+		grids.insert(handle, _Grid);
+		if pressed {
+			keys.press(PlayerCamera::FIT_KEY);
+		}
+		app.insert_resource(grids);
+		app.insert_resource(keys);
+		app.add_systems(Update, PlayerCamera::fit_to_grid::<_Grid>);
+		app.world_mut().spawn(primary_window(Vec2::ZERO));
+
+		app
+	}
+
+	fn context(handle: Handle<_Grid>) -> GridContext<_Grid> {
+		GridContext {
+			grid: ComputeGrid {
+				min: ComputeGridNode::new(0, 0),
+				max: ComputeGridNode::new(159, 19),
+			},
+			..GridContext::from_handle(handle)
+		}
+	}
+
+	#[test]
+	fn center_on_grid_and_fit_wider_side() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle, true);
+		app.world_mut().spawn(context(handle));
+		let camera = app.world_mut().spawn(PlayerCamera).id();
+
+		app.update();
+
+		let entity = app.world().entity(camera);
+		assert_eq!(
+			(Some(Vec3::new(795., 95., 0.)), Some(2. * 1.1)),
+			(
+				entity.get::<Transform>().map(|t| t.translation),
+				entity.get::<OrthographicProjection>().map(|p| p.scale)
+			)
+		);
+	}
+
+	#[test]
+	fn do_nothing_when_fit_key_not_pressed() {
+		let handle = new_handle!(_Grid);
+		let mut app = setup(&handle, false);
+		app.world_mut().spawn(context(handle));
+		let camera = app.world_mut().spawn(PlayerCamera).id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::default()),
+			app.world().entity(camera).get::<Transform>()
+		);
+	}
+}
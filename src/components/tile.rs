@@ -1,5 +1,5 @@
 use super::{
-	clickable::{Clickable, MouseLeft, MouseRight},
+	clickable::{Clickable, KeyA, KeyG, MouseLeft, MouseMiddle, MouseRight},
 	tile_type::TileType,
 	use_asset::UseAsset,
 };
@@ -16,6 +16,9 @@ use std::path::Path;
 	TileType,
 	Clickable<MouseLeft>,
 	Clickable<MouseRight>,
+	Clickable<MouseMiddle>,
+	Clickable<KeyA>,
+	Clickable<KeyG>,
 )]
 pub struct Tile;
 
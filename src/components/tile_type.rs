@@ -15,6 +15,9 @@ pub enum TileTypeValue {
 	Obstacle,
 	Start,
 	End,
+	Waypoint,
+	AgentStart,
+	AgentGoal,
 }
 
 impl From<TileType> for UseAsset<ColorMaterial> {
@@ -24,12 +27,14 @@ impl From<TileType> for UseAsset<ColorMaterial> {
 			TileTypeValue::Obstacle => UseAsset::new(Path::new("tile_types/obstacle.json")),
 			TileTypeValue::Start => UseAsset::new(Path::new("tile_types/start.json")),
 			TileTypeValue::End => UseAsset::new(Path::new("tile_types/end.json")),
+			TileTypeValue::Waypoint => UseAsset::new(Path::new("tile_types/waypoint.json")),
+			TileTypeValue::AgentStart => UseAsset::new(Path::new("tile_types/agent_start.json")),
+			TileTypeValue::AgentGoal => UseAsset::new(Path::new("tile_types/agent_goal.json")),
 		}
 	}
 }
 
 impl TileType {
-	#[cfg(test)]
 	pub fn from_value(value: TileTypeValue) -> Self {
 		Self { value }
 	}
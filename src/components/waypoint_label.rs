@@ -0,0 +1,134 @@
+use super::{compute_path_method::PathMarkers, tile_type::TileType};
+use bevy::prelude::*;
+
+/// Visiting order of a waypoint, drawn as number on its tile
+#[derive(Component, Debug, PartialEq)]
+#[require(Transform(WaypointLabel::transform))]
+pub struct WaypointLabel(pub usize);
+
+impl WaypointLabel {
+	fn transform() -> Transform {
+		Transform::from_xyz(0., 0., 2.)
+	}
+
+	pub fn update(
+		mut commands: Commands,
+		tiles: Query<(Entity, &Transform, Ref<TileType>)>,
+		labels: Query<Entity, With<Self>>,
+		mut removed_tiles: RemovedComponents<TileType>,
+		mut markers: Local<PathMarkers>,
+	) {
+		if !markers.update_on_change(tiles, &mut removed_tiles) {
+			return;
+		}
+
+		for entity in &labels {
+			commands.entity(entity).despawn_recursive();
+		}
+
+		for (index, waypoint) in markers.waypoints().enumerate() {
+			let Some(mut entity) = commands.get_entity(waypoint) else {
+				continue;
+			};
+			let order = index + 1;
+
+			entity.with_child((Self(order), Text2d::new(order.to_string())));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{components::tile_type::TileTypeValue, test_tools::SingleThreaded};
+	use std::ops::DerefMut;
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+		app.add_systems(Update, WaypointLabel::update);
+
+		app
+	}
+
+	fn label_of(app: &App, tile: Entity) -> Option<&WaypointLabel> {
+		app.world()
+			.iter_entities()
+			.filter(|entity| entity.get::<Parent>().map(Parent::get) == Some(tile))
+			.find_map(|entity| entity.get::<WaypointLabel>())
+	}
+
+	fn spawn_waypoint(app: &mut App, x: f32) -> Entity {
+		app.world_mut()
+			.spawn((
+				TileType::from_value(TileTypeValue::Waypoint),
+				Transform::from_xyz(x, 0., 0.),
+			))
+			.id()
+	}
+
+	#[test]
+	fn label_waypoints_in_placement_order() {
+		let mut app = setup();
+		let first = spawn_waypoint(&mut app, 1.);
+		app.update();
+		let second = spawn_waypoint(&mut app, 2.);
+		app.update();
+
+		assert_eq!(
+			(Some(&WaypointLabel(1)), Some(&WaypointLabel(2))),
+			(label_of(&app, first), label_of(&app, second))
+		);
+	}
+
+	#[test]
+	fn keep_order_when_waypoint_changed() {
+		let mut app = setup();
+		let first = spawn_waypoint(&mut app, 1.);
+		let second = spawn_waypoint(&mut app, 2.);
+		app.update();
+		app.world_mut()
+			.entity_mut(first)
+			.get_mut::<TileType>()
+			.unwrap()
+			.deref_mut();
+		app.update();
+
+		assert_eq!(
+			(Some(&WaypointLabel(1)), Some(&WaypointLabel(2))),
+			(label_of(&app, first), label_of(&app, second))
+		);
+	}
+
+	#[test]
+	fn renumber_when_waypoint_removed() {
+		let mut app = setup();
+		let first = spawn_waypoint(&mut app, 1.);
+		let second = spawn_waypoint(&mut app, 2.);
+		app.update();
+		app.world_mut()
+			.entity_mut(first)
+			.insert(TileType::from_value(TileTypeValue::Walkable));
+		app.update();
+
+		assert_eq!(
+			(None, Some(&WaypointLabel(1))),
+			(label_of(&app, first), label_of(&app, second))
+		);
+	}
+
+	#[test]
+	fn draw_order_as_text() {
+		let mut app = setup();
+		let waypoint = spawn_waypoint(&mut app, 1.);
+		app.update();
+
+		let text = app
+			.world()
+			.iter_entities()
+			.filter(|entity| entity.get::<Parent>().map(Parent::get) == Some(waypoint))
+			.find_map(|entity| entity.get::<Text2d>())
+			.map(|Text2d(text)| text.as_str());
+
+		assert_eq!(Some("1"), text);
+	}
+}
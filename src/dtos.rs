@@ -1,3 +1,7 @@
+pub mod chunked_grid_layout;
 pub mod grid_layout;
 pub mod mesh_definition;
+pub mod path_export;
+pub mod path_query;
+pub mod scenario;
 pub mod tile_color;
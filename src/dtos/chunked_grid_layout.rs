@@ -0,0 +1,27 @@
+use crate::{assets::chunked_grid::ChunkedGrid, traits::load_from::LoadFrom};
+use bevy::asset::LoadContext;
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct ChunkedGridLayout {
+	chunk_size: u32,
+	load_distance: u32,
+	scale: f32,
+}
+
+impl LoadFrom<ChunkedGridLayout> for ChunkedGrid {
+	fn load_from(
+		ChunkedGridLayout {
+			chunk_size,
+			load_distance,
+			scale,
+		}: ChunkedGridLayout,
+		_: &mut LoadContext,
+	) -> Self {
+		ChunkedGrid {
+			chunk_size,
+			load_distance,
+			scale,
+		}
+	}
+}
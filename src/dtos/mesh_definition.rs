@@ -11,6 +11,7 @@ pub struct MeshDefinition {
 enum Shape {
 	Tile { width: f32, height: f32 },
 	Line { width: f32 },
+	Arrow { width: f32, length: f32 },
 }
 
 impl LoadFrom<MeshDefinition> for Mesh {
@@ -18,6 +19,11 @@ impl LoadFrom<MeshDefinition> for Mesh {
 		match shape {
 			Shape::Tile { width, height } => Mesh::from(Rectangle::new(width, height)),
 			Shape::Line { width } => Mesh::from(Rectangle::new(width, 1.)),
+			Shape::Arrow { width, length } => Mesh::from(Triangle2d::new(
+				Vec2::new(-width / 2., -length / 2.),
+				Vec2::new(width / 2., -length / 2.),
+				Vec2::new(0., length / 2.),
+			)),
 		}
 	}
 }
@@ -33,6 +39,10 @@ impl LoadFrom<MeshDefinition> for ColliderDefinition {
 				half_height: width / 2.,
 				half_width: 0.5,
 			},
+			Shape::Arrow { width, length } => ColliderDefinition {
+				half_height: length / 2.,
+				half_width: width / 2.,
+			},
 		}
 	}
 }
@@ -0,0 +1,127 @@
+use super::path_query::{to_array, to_node, PathQuery};
+use crate::{
+	components::computed_path::ComputedRoute,
+	errors::{LoadError, WriteError},
+	traits::computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+};
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+/// Exported files are valid [`Scenario`](super::scenario::Scenario)s
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PathExport {
+	#[serde(flatten)]
+	pub query: PathQuery,
+	pub path: Vec<[i32; 2]>,
+}
+
+impl PathExport {
+	/// Returns `None`, when the route has no start and end.
+	pub fn new(
+		method: &str,
+		grid: ComputeGrid,
+		obstacles: &Obstacles,
+		ComputedRoute { route, path }: &ComputedRoute,
+	) -> Option<Self> {
+		Some(Self {
+			query: PathQuery::new(method, grid, obstacles, route)?,
+			path: path.iter().copied().map(to_array).collect(),
+		})
+	}
+
+	pub fn path(&self) -> Vec<ComputeGridNode> {
+		self.path.iter().copied().map(to_node).collect()
+	}
+
+	pub fn read(file: &Path) -> Result<Self, LoadError> {
+		let json = fs::read_to_string(file).map_err(LoadError::IO)?;
+
+		serde_json::from_str(&json).map_err(LoadError::ParseObject)
+	}
+
+	pub fn write(&self, file: &Path) -> Result<(), WriteError> {
+		let json = serde_json::to_string_pretty(self).map_err(WriteError::SerializeObject)?;
+
+		if let Some(directory) = file.parent() {
+			fs::create_dir_all(directory).map_err(WriteError::IO)?;
+		}
+
+		fs::write(file, json).map_err(WriteError::IO)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		components::compute_path_method::theta_star::ThetaStar,
+		traits::compute_path::{ComputePath, NewComputer},
+	};
+	use std::env::temp_dir;
+
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(4, 4),
+	};
+
+	fn route() -> ComputedRoute {
+		ComputedRoute {
+			route: vec![ComputeGridNode::new(0, 0), ComputeGridNode::new(4, 4)],
+			path: vec![
+				ComputeGridNode::new(0, 0),
+				ComputeGridNode::new(4, 0),
+				ComputeGridNode::new(4, 4),
+			],
+		}
+	}
+
+	#[test]
+	fn export_query_and_path() {
+		let obstacles = Obstacles::from_nodes(GRID, [ComputeGridNode::new(2, 2)]);
+
+		let export = PathExport::new("ThetaStar", GRID, &obstacles, &route());
+
+		assert_eq!(
+			Some(PathExport {
+				query: PathQuery {
+					method: "ThetaStar".to_owned(),
+					min: [0, 0],
+					max: [4, 4],
+					obstacles: vec![[2, 2]],
+					start: [0, 0],
+					end: [4, 4],
+					waypoints: vec![],
+				},
+				path: vec![[0, 0], [4, 0], [4, 4]],
+			}),
+			export
+		);
+	}
+
+	#[test]
+	fn reproduce_path() {
+		let obstacles = Obstacles::from_nodes(GRID, [ComputeGridNode::new(2, 1)]);
+		let start = ComputeGridNode::new(0, 1);
+		let end = ComputeGridNode::new(4, 1);
+		let route = ComputedRoute {
+			route: vec![start, end],
+			path: <ThetaStar>::new(GRID, obstacles.clone()).path(start, end),
+		};
+		let export = PathExport::new("ThetaStar", GRID, &obstacles, &route).unwrap();
+
+		assert_eq!(export.path(), export.query.compute::<ThetaStar>());
+	}
+
+	#[test]
+	fn write_and_read() -> Result<(), Box<dyn std::error::Error>> {
+		let file = temp_dir()
+			.join("project_zyheeda_pathfinding_path_export")
+			.join("write_and_read.json");
+		let export = PathExport::new("ThetaStar", GRID, &Obstacles::new(GRID), &route()).unwrap();
+
+		export.write(&file)?;
+
+		assert_eq!(export, PathExport::read(&file)?);
+		Ok(())
+	}
+}
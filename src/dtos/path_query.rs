@@ -0,0 +1,180 @@
+use crate::{
+	components::compute_path_method::chain_legs,
+	traits::{
+		computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+		compute_path::{ComputePath, NewComputer},
+	},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PathQuery {
+	pub method: String,
+	pub min: [i32; 2],
+	pub max: [i32; 2],
+	pub obstacles: Vec<[i32; 2]>,
+	pub start: [i32; 2],
+	pub end: [i32; 2],
+	/// In placement order
+	#[serde(default)]
+	pub waypoints: Vec<[i32; 2]>,
+}
+
+impl PathQuery {
+	/// Returns `None`, when the route has no start and end.
+	pub fn new(
+		method: &str,
+		grid: ComputeGrid,
+		obstacles: &Obstacles,
+		route: &[ComputeGridNode],
+	) -> Option<Self> {
+		let [start, waypoints @ .., end] = route else {
+			return None;
+		};
+
+		Some(Self {
+			method: method.to_owned(),
+			min: to_array(grid.min),
+			max: to_array(grid.max),
+			obstacles: obstacles.iter().map(to_array).collect(),
+			start: to_array(*start),
+			end: to_array(*end),
+			waypoints: waypoints.iter().copied().map(to_array).collect(),
+		})
+	}
+
+	pub fn grid(&self) -> ComputeGrid {
+		ComputeGrid {
+			min: to_node(self.min),
+			max: to_node(self.max),
+		}
+	}
+
+	pub fn obstacles(&self) -> Obstacles {
+		Obstacles::from_nodes(self.grid(), self.obstacles.iter().copied().map(to_node))
+	}
+
+	pub fn route(&self) -> Vec<ComputeGridNode> {
+		[self.start]
+			.iter()
+			.chain(&self.waypoints)
+			.chain([&self.end])
+			.copied()
+			.map(to_node)
+			.collect()
+	}
+
+	/// Ignores [`PathQuery::method`]
+	pub fn compute<TMethod>(&self) -> Vec<ComputeGridNode>
+	where
+		TMethod: NewComputer + ComputePath,
+	{
+		let method = TMethod::new(self.grid(), self.obstacles());
+		let (_, path) = chain_legs(&method, &self.route());
+
+		path
+	}
+}
+
+pub(crate) fn to_array(ComputeGridNode { x, y }: ComputeGridNode) -> [i32; 2] {
+	[x, y]
+}
+
+pub(crate) fn to_node([x, y]: [i32; 2]) -> ComputeGridNode {
+	ComputeGridNode::new(x, y)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::compute_path_method::{a_star::AStar, theta_star::ThetaStar};
+
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(4, 4),
+	};
+
+	fn route() -> Vec<ComputeGridNode> {
+		vec![
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(4, 0),
+			ComputeGridNode::new(4, 4),
+		]
+	}
+
+	#[test]
+	fn query_from_grid_and_route() {
+		let obstacles = Obstacles::from_nodes(GRID, [ComputeGridNode::new(2, 2)]);
+
+		let query = PathQuery::new("ThetaStar", GRID, &obstacles, &route());
+
+		assert_eq!(
+			Some(PathQuery {
+				method: "ThetaStar".to_owned(),
+				min: [0, 0],
+				max: [4, 4],
+				obstacles: vec![[2, 2]],
+				start: [0, 0],
+				end: [4, 4],
+				waypoints: vec![[4, 0]],
+			}),
+			query
+		);
+	}
+
+	#[test]
+	fn no_query_without_start_and_end() {
+		let query = PathQuery::new(
+			"ThetaStar",
+			GRID,
+			&Obstacles::new(GRID),
+			&[ComputeGridNode::new(0, 0)],
+		);
+
+		assert_eq!(None, query);
+	}
+
+	#[test]
+	fn restore_grid_obstacles_and_route() {
+		let obstacles = Obstacles::from_nodes(GRID, [ComputeGridNode::new(2, 2)]);
+		let query = PathQuery::new("ThetaStar", GRID, &obstacles, &route()).unwrap();
+
+		assert_eq!(
+			(GRID, obstacles, route()),
+			(query.grid(), query.obstacles(), query.route())
+		);
+	}
+
+	#[test]
+	fn compute_like_method() {
+		let obstacles = Obstacles::from_nodes(GRID, [ComputeGridNode::new(2, 1)]);
+		let start = ComputeGridNode::new(0, 1);
+		let end = ComputeGridNode::new(4, 1);
+		let query = PathQuery::new("ThetaStar", GRID, &obstacles, &[start, end]).unwrap();
+
+		assert_eq!(
+			<ThetaStar>::new(GRID, obstacles).path(start, end),
+			query.compute::<ThetaStar>()
+		);
+	}
+
+	#[test]
+	fn compute_through_waypoints() {
+		let query = PathQuery::new("AStar", GRID, &Obstacles::new(GRID), &route()).unwrap();
+
+		assert_eq!(
+			vec![
+				ComputeGridNode::new(0, 0),
+				ComputeGridNode::new(1, 0),
+				ComputeGridNode::new(2, 0),
+				ComputeGridNode::new(3, 0),
+				ComputeGridNode::new(4, 0),
+				ComputeGridNode::new(4, 1),
+				ComputeGridNode::new(4, 2),
+				ComputeGridNode::new(4, 3),
+				ComputeGridNode::new(4, 4),
+			],
+			query.compute::<AStar>()
+		);
+	}
+}
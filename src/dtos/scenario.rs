@@ -0,0 +1,353 @@
+use super::path_query::{to_array, PathQuery};
+use crate::{
+	components::compute_path_method::{
+		a_star::AStar,
+		anytime_a_star::AnytimeAStar,
+		bidirectional_a_star::BidirectionalAStar,
+		cooperative_a_star::CooperativeAStar,
+		lazy_theta_star::LazyThetaStar,
+		straight_line::StraightLine,
+		straight_line_wide::StraightLineWide,
+		theta_star::ThetaStar,
+		weighted_a_star::WeightedAStar,
+	},
+	errors::{LoadError, ScenarioError},
+	traits::{
+		computable_grid::ComputeGridNode,
+		cost::Fixed,
+		heuristic::{landmarks::Landmarks, Chebyshev, Euclidean, Manhattan, Octile, Zero},
+	},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+	fs,
+	io::Error as IOError,
+	path::{Path, PathBuf},
+};
+
+macro_rules! compute_with_heuristic {
+	($query:expr, $method:ident, $heuristic:expr $(, $cost:ty)?) => {
+		match $heuristic {
+			"Landmarks" => Some($query.compute::<$method<Landmarks $(, $cost)?>>()),
+			heuristic => compute_with_grid_distance!($query, $method, heuristic $(, $cost)?),
+		}
+	};
+}
+
+macro_rules! compute_with_grid_distance {
+	($query:expr, $method:ident, $heuristic:expr $(, $cost:ty)?) => {
+		match $heuristic {
+			"Chebyshev" => Some($query.compute::<$method<Chebyshev $(, $cost)?>>()),
+			"Euclidean" => Some($query.compute::<$method<Euclidean $(, $cost)?>>()),
+			"Manhattan" => Some($query.compute::<$method<Manhattan $(, $cost)?>>()),
+			"Octile" => Some($query.compute::<$method<Octile $(, $cost)?>>()),
+			"Zero" => Some($query.compute::<$method<Zero $(, $cost)?>>()),
+			_ => None,
+		}
+	};
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Scenario {
+	#[serde(flatten)]
+	pub query: PathQuery,
+	#[serde(default)]
+	pub path: Option<Vec<[i32; 2]>>,
+	#[serde(default)]
+	pub cost: Option<f32>,
+}
+
+impl Scenario {
+	const COST_TOLERANCE: f32 = 0.001;
+
+	pub fn read(file: &Path) -> Result<Self, LoadError> {
+		let json = fs::read_to_string(file).map_err(LoadError::IO)?;
+
+		serde_json::from_str(&json).map_err(LoadError::ParseObject)
+	}
+
+	pub fn files_in(directory: &Path) -> Result<Vec<PathBuf>, IOError> {
+		let mut files = fs::read_dir(directory)?
+			.map(|entry| Ok(entry?.path()))
+			.collect::<Result<Vec<_>, IOError>>()?;
+		files.retain(|file| {
+			file.extension()
+				.is_some_and(|extension| extension == "json")
+		});
+		files.sort();
+
+		Ok(files)
+	}
+
+	/// Type parameters in angle brackets, like `ThetaStar<Octile, Fixed>`
+	pub fn compute(&self) -> Result<Vec<ComputeGridNode>, ScenarioError> {
+		let method = self.query.method.as_str();
+		let (name, parameters) = match method.strip_suffix('>').and_then(|m| m.split_once('<')) {
+			Some((name, parameters)) => (name, parameters.split(", ").collect()),
+			None => (method, vec![]),
+		};
+		let path = match (name, parameters.as_slice()) {
+			("AStar", []) => Some(self.query.compute::<AStar>()),
+			("AStar", [heuristic]) => compute_with_heuristic!(self.query, AStar, *heuristic),
+			("AStar", [heuristic, "f32"]) => {
+				compute_with_heuristic!(self.query, AStar, *heuristic, f32)
+			}
+			("AStar", [heuristic, "Fixed"]) => {
+				compute_with_heuristic!(self.query, AStar, *heuristic, Fixed)
+			}
+			("LazyThetaStar", []) => Some(self.query.compute::<LazyThetaStar>()),
+			("LazyThetaStar", [heuristic]) => {
+				compute_with_grid_distance!(self.query, LazyThetaStar, *heuristic)
+			}
+			("LazyThetaStar", [heuristic, "f32"]) => {
+				compute_with_grid_distance!(self.query, LazyThetaStar, *heuristic, f32)
+			}
+			("LazyThetaStar", [heuristic, "Fixed"]) => {
+				compute_with_grid_distance!(self.query, LazyThetaStar, *heuristic, Fixed)
+			}
+			("ThetaStar", []) => Some(self.query.compute::<ThetaStar>()),
+			("ThetaStar", [heuristic]) => {
+				compute_with_grid_distance!(self.query, ThetaStar, *heuristic)
+			}
+			("ThetaStar", [heuristic, "f32"]) => {
+				compute_with_grid_distance!(self.query, ThetaStar, *heuristic, f32)
+			}
+			("ThetaStar", [heuristic, "Fixed"]) => {
+				compute_with_grid_distance!(self.query, ThetaStar, *heuristic, Fixed)
+			}
+			("WeightedAStar", []) => Some(self.query.compute::<WeightedAStar>()),
+			("WeightedAStar", [heuristic]) => {
+				compute_with_heuristic!(self.query, WeightedAStar, *heuristic)
+			}
+			("WeightedAStar", [heuristic, "f32"]) => {
+				compute_with_heuristic!(self.query, WeightedAStar, *heuristic, f32)
+			}
+			("WeightedAStar", [heuristic, "Fixed"]) => {
+				compute_with_heuristic!(self.query, WeightedAStar, *heuristic, Fixed)
+			}
+			("AnytimeAStar", []) => Some(self.query.compute::<AnytimeAStar>()),
+			("AnytimeAStar", [heuristic]) => {
+				compute_with_heuristic!(self.query, AnytimeAStar, *heuristic)
+			}
+			("AnytimeAStar", [heuristic, "f32"]) => {
+				compute_with_heuristic!(self.query, AnytimeAStar, *heuristic, f32)
+			}
+			("AnytimeAStar", [heuristic, "Fixed"]) => {
+				compute_with_heuristic!(self.query, AnytimeAStar, *heuristic, Fixed)
+			}
+			("BidirectionalAStar", []) => Some(self.query.compute::<BidirectionalAStar>()),
+			("BidirectionalAStar", [heuristic]) => {
+				compute_with_heuristic!(self.query, BidirectionalAStar, *heuristic)
+			}
+			("BidirectionalAStar", [heuristic, "f32"]) => {
+				compute_with_heuristic!(self.query, BidirectionalAStar, *heuristic, f32)
+			}
+			("BidirectionalAStar", [heuristic, "Fixed"]) => {
+				compute_with_heuristic!(self.query, BidirectionalAStar, *heuristic, Fixed)
+			}
+			("CooperativeAStar", []) => Some(self.query.compute::<CooperativeAStar>()),
+			("StraightLine", []) => Some(self.query.compute::<StraightLine>()),
+			("StraightLineWide", []) => Some(self.query.compute::<StraightLineWide>()),
+			_ => None,
+		};
+
+		path.ok_or_else(|| ScenarioError::UnknownMethod(method.to_owned()))
+	}
+
+	pub fn run(&self) -> Result<(), ScenarioError> {
+		if self.path.is_none() && self.cost.is_none() {
+			return Err(ScenarioError::NoExpectation);
+		}
+
+		let path = self.compute()?;
+
+		if let Some(expected) = &self.path {
+			let actual = path.iter().copied().map(to_array).collect::<Vec<_>>();
+			if expected != &actual {
+				return Err(ScenarioError::UnexpectedPath {
+					expected: expected.clone(),
+					actual,
+				});
+			}
+		}
+
+		if let Some(expected) = self.cost {
+			let actual = Self::cost(&path);
+			if (expected - actual).abs() > Self::COST_TOLERANCE {
+				return Err(ScenarioError::UnexpectedCost { expected, actual });
+			}
+		}
+
+		Ok(())
+	}
+
+	pub fn cost(path: &[ComputeGridNode]) -> f32 {
+		path.windows(2)
+			.map(|pair| {
+				let [a, b] = pair else {
+					return 0.;
+				};
+				let delta = *b - *a;
+				((delta.x * delta.x + delta.y * delta.y) as f32).sqrt()
+			})
+			.sum()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::dtos::path_export::PathExport;
+
+	fn scenario(method: &str, path: Option<Vec<[i32; 2]>>, cost: Option<f32>) -> Scenario {
+		Scenario {
+			query: PathQuery {
+				method: method.to_owned(),
+				min: [0, 0],
+				max: [2, 2],
+				obstacles: vec![[1, 1]],
+				start: [0, 0],
+				end: [2, 2],
+				waypoints: vec![],
+			},
+			path,
+			cost,
+		}
+	}
+
+	#[test]
+	fn parse_scenario() -> Result<(), serde_json::Error> {
+		let json = r#"{
+			"method": "AStar",
+			"min": [0, 0],
+			"max": [2, 2],
+			"obstacles": [[1, 1]],
+			"start": [0, 0],
+			"end": [2, 2],
+			"cost": 4.0
+		}"#;
+
+		let parsed = serde_json::from_str::<Scenario>(json)?;
+
+		assert_eq!(scenario("AStar", None, Some(4.)), parsed);
+		Ok(())
+	}
+
+	#[test]
+	fn parse_path_export() -> Result<(), serde_json::Error> {
+		let export = PathExport {
+			query: scenario("AStar", None, None).query,
+			path: vec![[0, 0], [0, 1], [0, 2], [1, 2], [2, 2]],
+		};
+		let json = serde_json::to_string(&export)?;
+
+		let parsed = serde_json::from_str::<Scenario>(&json)?;
+
+		assert_eq!(
+			scenario(
+				"AStar",
+				Some(vec![[0, 0], [0, 1], [0, 2], [1, 2], [2, 2]]),
+				None
+			),
+			parsed
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn pass_with_expected_path() {
+		let scenario = scenario(
+			"AStar",
+			Some(vec![[0, 0], [0, 1], [0, 2], [1, 2], [2, 2]]),
+			None,
+		);
+
+		assert_eq!(Ok(()), scenario.run());
+	}
+
+	#[test]
+	fn fail_with_unexpected_path() {
+		let scenario = scenario("AStar", Some(vec![[0, 0], [2, 2]]), None);
+
+		assert_eq!(
+			Err(ScenarioError::UnexpectedPath {
+				expected: vec![[0, 0], [2, 2]],
+				actual: vec![[0, 0], [0, 1], [0, 2], [1, 2], [2, 2]],
+			}),
+			scenario.run()
+		);
+	}
+
+	#[test]
+	fn pass_with_expected_cost() {
+		let scenario = scenario("AStar", None, Some(4.));
+
+		assert_eq!(Ok(()), scenario.run());
+	}
+
+	#[test]
+	fn fail_with_unexpected_cost() {
+		let scenario = scenario("AStar", None, Some(3.5));
+
+		assert_eq!(
+			Err(ScenarioError::UnexpectedCost {
+				expected: 3.5,
+				actual: 4.,
+			}),
+			scenario.run()
+		);
+	}
+
+	#[test]
+	fn fail_without_expectation() {
+		let scenario = scenario("AStar", None, None);
+
+		assert_eq!(Err(ScenarioError::NoExpectation), scenario.run());
+	}
+
+	#[test]
+	fn pass_with_heuristic() {
+		let scenario = scenario("AStar<Zero>", None, Some(4.));
+
+		assert_eq!(Ok(()), scenario.run());
+	}
+
+	#[test]
+	fn pass_with_heuristic_and_cost() {
+		let scenario = scenario("ThetaStar<Octile, Fixed>", None, Some(4.));
+
+		assert_eq!(Ok(()), scenario.run());
+	}
+
+	#[test]
+	fn fail_with_unknown_heuristic() {
+		let scenario = scenario("AStar<Unknown>", None, Some(4.));
+
+		assert_eq!(
+			Err(ScenarioError::UnknownMethod("AStar<Unknown>".to_owned())),
+			scenario.run()
+		);
+	}
+
+	#[test]
+	fn fail_with_heuristic_for_method_without_heuristic() {
+		let scenario = scenario("StraightLine<Zero>", None, Some(4.));
+
+		assert_eq!(
+			Err(ScenarioError::UnknownMethod(
+				"StraightLine<Zero>".to_owned()
+			)),
+			scenario.run()
+		);
+	}
+
+	#[test]
+	fn fail_with_unknown_method() {
+		let scenario = scenario("Unknown", None, Some(0.));
+
+		assert_eq!(
+			Err(ScenarioError::UnknownMethod("Unknown".to_owned())),
+			scenario.run()
+		);
+	}
+}
@@ -42,3 +42,58 @@ impl Error for LoadError {
 		}
 	}
 }
+
+#[derive(Debug, TypePath)]
+pub enum WriteError {
+	IO(IOError),
+	SerializeObject(SerdeJsonError),
+}
+
+impl Display for WriteError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			WriteError::IO(err) => write!(f, "Failed to write file: {}", err),
+			WriteError::SerializeObject(err) => write!(f, "Failed to serialize data: {}", err),
+		}
+	}
+}
+
+impl Error for WriteError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			WriteError::IO(err) => Some(err),
+			WriteError::SerializeObject(err) => Some(err),
+		}
+	}
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ScenarioError {
+	UnknownMethod(String),
+	NoExpectation,
+	UnexpectedPath {
+		expected: Vec<[i32; 2]>,
+		actual: Vec<[i32; 2]>,
+	},
+	UnexpectedCost {
+		expected: f32,
+		actual: f32,
+	},
+}
+
+impl Display for ScenarioError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+		match self {
+			ScenarioError::UnknownMethod(method) => write!(f, "Unknown method: {}", method),
+			ScenarioError::NoExpectation => write!(f, "Neither path nor cost expected"),
+			ScenarioError::UnexpectedPath { expected, actual } => {
+				write!(f, "Expected path {:?}, got {:?}", expected, actual)
+			}
+			ScenarioError::UnexpectedCost { expected, actual } => {
+				write!(f, "Expected cost {}, got {}", expected, actual)
+			}
+		}
+	}
+}
+
+impl Error for ScenarioError {}
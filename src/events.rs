@@ -0,0 +1,2 @@
+pub mod path_request;
+pub mod path_result;
@@ -0,0 +1,113 @@
+use crate::components::{
+	compute_path_method::{PathMarkers, PendingRequest},
+	computed_path::ComputedPath,
+	grid_context::GridContext,
+	tile_type::TileType,
+};
+use bevy::prelude::*;
+use std::any::TypeId;
+
+/// Asks the [`ComputePathMethod`](crate::components::compute_path_method::ComputePathMethod)
+/// of `grid` for a path, answered with a [`PathResult`](super::path_result::PathResult).
+///
+/// A new request of the same `requester` on the same `grid` cancels the previous one.
+#[derive(Event, Debug, PartialEq, Clone)]
+pub struct PathRequest {
+	pub requester: Entity,
+	pub grid: Entity,
+	pub start: Vec2,
+	pub end: Vec2,
+	pub options: PathOptions,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PathOptions {
+	/// Positions to pass through in the given order.
+	pub waypoints: Vec<Vec2>,
+	/// Spawn the result as [`ComputedPath`] of the grid, replacing the one drawn before.
+	pub draw: bool,
+	/// Type of the method that answers, when the grid has several. By default all of
+	/// them answer.
+	pub method: Option<TypeId>,
+}
+
+impl PathOptions {
+	pub fn with_method<TMethod>(self) -> Self
+	where
+		TMethod: 'static,
+	{
+		Self {
+			method: Some(TypeId::of::<TMethod>()),
+			..self
+		}
+	}
+
+	pub fn accepts<TMethod>(&self) -> bool
+	where
+		TMethod: 'static,
+	{
+		self.method
+			.is_none_or(|method| method == TypeId::of::<TMethod>())
+	}
+}
+
+impl PathRequest {
+	/// Route from start over waypoints to end.
+	pub fn route(&self) -> Vec<Vec2> {
+		[self.start]
+			.into_iter()
+			.chain(self.options.waypoints.iter().copied())
+			.chain([self.end])
+			.collect()
+	}
+
+	/// Requests a drawn path for each grid, whenever start, end, waypoints or obstacles
+	/// of the tiles change. Each grid is its own requester.
+	///
+	/// Without start or end, pending requests of the grids are cancelled and their
+	/// paths removed.
+	#[allow(clippy::too_many_arguments)]
+	pub fn from_tiles<TGrid>(
+		mut commands: Commands,
+		mut requests: EventWriter<Self>,
+		grids: Query<Entity, With<GridContext<TGrid>>>,
+		pending: Query<(Entity, &PendingRequest)>,
+		computed_paths: Query<(Entity, &Parent), With<ComputedPath>>,
+		tiles: Query<(Entity, &Transform, Ref<TileType>)>,
+		mut removed_tiles: RemovedComponents<TileType>,
+		mut markers: Local<PathMarkers>,
+	) where
+		TGrid: Asset,
+	{
+		if !markers.update_on_change(tiles, &mut removed_tiles) {
+			return;
+		}
+
+		match markers.route() {
+			Some(route) => {
+				for grid in &grids {
+					let [start, waypoints @ .., end] = route.as_slice() else {
+						continue;
+					};
+					requests.send(Self {
+						requester: grid,
+						grid,
+						start: *start,
+						end: *end,
+						options: PathOptions {
+							waypoints: waypoints.to_vec(),
+							draw: true,
+							..default()
+						},
+					});
+				}
+			}
+			None => {
+				for grid in &grids {
+					PendingRequest::cancel(&mut commands, grid, grid, &pending);
+					ComputedPath::despawn_of(&mut commands, grid, &computed_paths);
+				}
+			}
+		}
+	}
+}
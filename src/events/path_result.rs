@@ -0,0 +1,9 @@
+use super::path_request::PathRequest;
+use bevy::prelude::*;
+
+#[derive(Event, Debug, PartialEq, Clone)]
+pub struct PathResult {
+	pub request: PathRequest,
+	/// World positions from start to end, empty when the end cannot be reached.
+	pub path: Vec<Vec2>,
+}
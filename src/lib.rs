@@ -1,13 +1,25 @@
+#[cfg(feature = "app")]
 pub mod asset_loader;
+#[cfg(feature = "app")]
 pub mod assets;
 pub mod components;
+#[cfg(feature = "app")]
 pub mod dtos;
+#[cfg(feature = "app")]
 pub mod errors;
+#[cfg(feature = "app")]
+pub mod events;
+#[cfg(feature = "app")]
 pub mod parsers;
+#[cfg(feature = "app")]
+pub mod plugins;
+#[cfg(feature = "app")]
 pub mod resources;
+#[cfg(feature = "app")]
 pub mod states;
+#[cfg(feature = "app")]
 pub mod systems;
 pub mod traits;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "app"))]
 mod test_tools;
@@ -0,0 +1,4 @@
+pub mod chunked_grid;
+pub mod editor;
+pub mod pathfinding;
+pub mod tile_grid;
@@ -0,0 +1,39 @@
+use super::editor::TileSystems;
+use crate::{
+	asset_loader::CustomAssetLoader,
+	assets::chunked_grid::ChunkedGrid,
+	components::grid_chunks::GridChunks,
+	dtos::chunked_grid_layout::ChunkedGridLayout,
+};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Spawns and despawns the tiles of [`ChunkedGrid`]s in chunks around the `TCamera`
+/// and keeps their [`GridContext`](crate::components::grid_context::GridContext)
+/// in sync with the loaded chunks.
+pub struct ChunkedGridPlugin<TCamera>(PhantomData<TCamera>);
+
+impl<TCamera> Default for ChunkedGridPlugin<TCamera> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<TCamera> Plugin for ChunkedGridPlugin<TCamera>
+where
+	TCamera: Component,
+{
+	fn build(&self, app: &mut App) {
+		app.register_asset_loader(CustomAssetLoader::<ChunkedGrid, ChunkedGridLayout>::default())
+			.add_systems(
+				Update,
+				(
+					GridChunks::load_around::<TCamera>,
+					GridChunks::track_obstacles,
+					GridChunks::update_context,
+				)
+					.chain()
+					.in_set(TileSystems),
+			);
+	}
+}
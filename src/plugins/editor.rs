@@ -0,0 +1,155 @@
+use super::pathfinding::PathfindingSystems;
+use crate::{
+	asset_loader::CustomAssetLoader,
+	assets::collider_definition::ColliderDefinition,
+	components::{
+		agent::Agent,
+		clickable::{Clickable, KeyA, KeyG, MouseLeft, MouseMiddle, MouseRight},
+		computed_path::{ComputedPath, PathNodeConnection},
+		despawn::Despawn,
+		flow_field::FlowField,
+		multi_agent_plan::MultiAgentPlan,
+		path_stats::PathStatsPanel,
+		player_camera::PlayerCamera,
+		tile_collider::TileCollider,
+		tile_type::{TileType, TileTypeValue},
+		use_asset::UseAsset,
+		waypoint_label::WaypointLabel,
+	},
+	dtos::{mesh_definition::MeshDefinition, tile_color::TileColor},
+	events::path_request::PathRequest,
+	resources::{mouse_world_position::MouseWorldPosition, path_exporter::PathExporter},
+	states::path_placement::PathPlacement,
+	systems::spawn::Spawn,
+	traits::{
+		computable_grid::{GetComputeGridNode, GetTranslation},
+		into_component::IntoComponent,
+	},
+};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Requires the [`PathfindingPlugin`](super::pathfinding::PathfindingPlugin) for `TGrid`
+/// and a plugin spawning tiles in [`TileSystems`].
+pub struct EditorPlugin<TGrid>(PhantomData<TGrid>);
+
+impl<TGrid> Default for EditorPlugin<TGrid> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+/// Systems spawning and despawning tiles, run before [`PathfindingSystems`].
+#[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone)]
+pub struct TileSystems;
+
+impl<TGrid> Plugin for EditorPlugin<TGrid>
+where
+	TGrid: Asset + GetComputeGridNode + GetTranslation,
+	Handle<TGrid>: IntoComponent,
+{
+	fn build(&self, app: &mut App) {
+		app.init_state::<PathPlacement>()
+			.init_asset::<ColliderDefinition>()
+			.init_resource::<MouseWorldPosition>()
+			.init_resource::<PathExporter>()
+			.register_asset_loader(
+				CustomAssetLoader::<ColliderDefinition, MeshDefinition>::default(),
+			)
+			.register_asset_loader(CustomAssetLoader::<ColorMaterial, TileColor>::default())
+			.register_asset_loader(CustomAssetLoader::<Mesh, MeshDefinition>::default())
+			.add_systems(Startup, (PlayerCamera::spawn, PathStatsPanel::spawn))
+			.add_systems(
+				Update,
+				(
+					PlayerCamera::pan,
+					PlayerCamera::zoom,
+					PlayerCamera::fit_to_grid::<TGrid>,
+					MouseWorldPosition::update_using::<PlayerCamera>,
+				)
+					.chain(),
+			)
+			.add_systems(
+				Update,
+				(
+					UseAsset::<Mesh>::insert,
+					UseAsset::<TGrid>::insert,
+					UseAsset::<ColliderDefinition>::insert,
+					UseAsset::<ColorMaterial>::insert.after(TileType::update_color),
+				),
+			)
+			.add_systems(Update, (Despawn::system, WaypointLabel::update))
+			.configure_sets(Update, TileSystems.before(PathfindingSystems))
+			.add_systems(
+				Update,
+				PathRequest::from_tiles::<TGrid>
+					.after(TileSystems)
+					.before(PathfindingSystems),
+			)
+			.add_systems(
+				Update,
+				(
+					FlowField::compute::<TGrid>,
+					FlowField::draw::<TGrid>,
+					MultiAgentPlan::compute::<TGrid>,
+					MultiAgentPlan::poll::<TGrid>,
+					MultiAgentPlan::draw,
+					ComputedPath::draw,
+					PathNodeConnection::draw,
+					PathNodeConnection::mark_blocked::<TGrid>,
+					PathStatsPanel::update,
+				)
+					.chain()
+					.after(PathfindingSystems),
+			)
+			.add_systems(
+				Update,
+				PathExporter::export_on_key::<TGrid>.after(PathfindingSystems),
+			)
+			.add_systems(
+				Update,
+				(
+					Agent::follow_new_paths,
+					Agent::spawn_on_path_start(90.),
+					Agent::walk,
+				)
+					.chain()
+					.after(PathfindingSystems),
+			)
+			.add_systems(
+				Update,
+				(
+					Clickable::<MouseLeft>::detect_click_on::<TileCollider>,
+					Clickable::<MouseRight>::detect_click_on::<TileCollider>,
+					Clickable::<MouseMiddle>::detect_click_on::<TileCollider>,
+					Clickable::<KeyA>::detect_click_on::<TileCollider>,
+					Clickable::<KeyG>::detect_click_on::<TileCollider>,
+				)
+					.after(MouseWorldPosition::update_using::<PlayerCamera>)
+					.run_if(not(PlayerCamera::is_panning)),
+			)
+			.add_systems(
+				Update,
+				(
+					PathPlacement::drag_on_hold::<MouseLeft>,
+					PathPlacement::reset_on_release::<MouseLeft>,
+					Clickable::<MouseRight>::toggle::<TileType>(TileTypeValue::Obstacle),
+					Clickable::<MouseMiddle>::toggle::<TileType>(TileTypeValue::Waypoint),
+					Clickable::<KeyA>::toggle::<TileType>(TileTypeValue::AgentStart),
+					Clickable::<KeyG>::toggle::<TileType>(TileTypeValue::AgentGoal),
+					Clickable::<MouseLeft>::switch_on_single::<TileType>(TileTypeValue::Start)
+						.run_if(
+							in_state(PathPlacement::Start)
+								.or(in_state(PathPlacement::Drag(Some(TileTypeValue::Start)))),
+						),
+					Clickable::<MouseLeft>::switch_on_single::<TileType>(TileTypeValue::End)
+						.run_if(
+							in_state(PathPlacement::End)
+								.or(in_state(PathPlacement::Drag(Some(TileTypeValue::End)))),
+						),
+					TileType::update_color,
+				)
+					.chain(),
+			);
+	}
+}
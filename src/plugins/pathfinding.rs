@@ -0,0 +1,325 @@
+use crate::{
+	components::{compute_path_method::ComputePathMethod, grid_context::GridContext},
+	events::{path_request::PathRequest, path_result::PathResult},
+	resources::expansion_budget::ExpansionBudget,
+	traits::{
+		computable_grid::{GetComputeGridNode, GetTranslation},
+		compute_path::{ComputePath, ComputePathSliced, NewComputer},
+	},
+};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Systems reacting to [`PathResult`]s should run after [`PathfindingSystems`].
+pub struct PathfindingPlugin<TGrid, TMethod, TMode = Async>(PhantomData<(TGrid, TMethod, TMode)>);
+
+impl<TGrid, TMethod, TMode> Default for PathfindingPlugin<TGrid, TMethod, TMode> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+pub struct Async;
+
+pub struct Sliced;
+
+#[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone)]
+pub struct PathfindingSystems;
+
+impl<TGrid, TMethod, TMode> PathfindingPlugin<TGrid, TMethod, TMode>
+where
+	TGrid: Asset + GetComputeGridNode,
+	TMethod: NewComputer + Sync + Send + 'static,
+{
+	fn build_common(app: &mut App) {
+		if !app.world().contains_resource::<Assets<TGrid>>() {
+			app.init_asset::<TGrid>();
+		}
+
+		app.add_event::<PathRequest>()
+			.add_event::<PathResult>()
+			.add_systems(
+				Update,
+				(
+					GridContext::<TGrid>::track_obstacles,
+					ComputePathMethod::<TGrid, TMethod>::instantiate,
+				)
+					.chain()
+					.in_set(PathfindingSystems),
+			);
+	}
+}
+
+impl<TGrid, TMethod> Plugin for PathfindingPlugin<TGrid, TMethod, Async>
+where
+	TGrid: Asset + GetComputeGridNode + GetTranslation,
+	TMethod: ComputePath + NewComputer + Sync + Send + 'static,
+{
+	fn build(&self, app: &mut App) {
+		Self::build_common(app);
+
+		app.add_systems(
+			Update,
+			(
+				ComputePathMethod::<TGrid, TMethod>::compute_path,
+				ComputePathMethod::<TGrid, TMethod>::poll_path,
+			)
+				.chain()
+				.after(ComputePathMethod::<TGrid, TMethod>::instantiate)
+				.in_set(PathfindingSystems),
+		);
+	}
+}
+
+impl<TGrid, TMethod> Plugin for PathfindingPlugin<TGrid, TMethod, Sliced>
+where
+	TGrid: Asset + GetComputeGridNode + GetTranslation,
+	TMethod: ComputePath + ComputePathSliced + NewComputer + Sync + Send + 'static,
+{
+	fn build(&self, app: &mut App) {
+		Self::build_common(app);
+
+		app.init_resource::<ExpansionBudget>().add_systems(
+			Update,
+			(
+				ComputePathMethod::<TGrid, TMethod>::compute_path_sliced,
+				ComputePathMethod::<TGrid, TMethod>::continue_sliced_path,
+			)
+				.chain()
+				.after(ComputePathMethod::<TGrid, TMethod>::instantiate)
+				.in_set(PathfindingSystems),
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		components::{
+			compute_path_method::anytime_a_star::AnytimeAStar,
+			despawn::Despawn,
+			path_stats::PathStats,
+		},
+		events::path_request::PathOptions,
+		traits::{
+			computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+			compute_path::SearchProgress,
+		},
+	};
+	use bevy::asset::AssetPlugin;
+
+	#[derive(Asset, TypePath)]
+	struct _Grid;
+
+	impl GetComputeGridNode for _Grid {
+		fn compute_grid_node(&self, Vec2 { x, y }: Vec2) -> Option<ComputeGridNode> {
+			Some(ComputeGridNode::new(x as i32, y as i32))
+		}
+	}
+
+	impl GetTranslation for _Grid {
+		fn translation(&self, ComputeGridNode { x, y }: ComputeGridNode) -> Option<Vec2> {
+			Some(Vec2::new(x as f32, y as f32))
+		}
+	}
+
+	struct _Method;
+
+	impl NewComputer for _Method {
+		fn new(_: ComputeGrid, _: Obstacles) -> Self {
+			_Method
+		}
+	}
+
+	impl ComputePath for _Method {
+		fn draw_connections(&self) -> bool {
+			false
+		}
+
+		fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode> {
+			vec![start, end]
+		}
+	}
+
+	/// Needs 100 expansions for each path
+	impl ComputePathSliced for _Method {
+		type TSearch = (ComputeGridNode, ComputeGridNode, usize);
+
+		fn start_search(&self, start: ComputeGridNode, end: ComputeGridNode) -> Self::TSearch {
+			(start, end, 100)
+		}
+
+		fn continue_search(&self, search: &mut Self::TSearch, budget: usize) -> SearchProgress {
+			let (start, end, remaining) = search;
+			let expanded = budget.min(*remaining);
+			*remaining -= expanded;
+
+			match remaining {
+				0 => SearchProgress::Done {
+					expanded,
+					path: vec![*start, *end],
+				},
+				_ => SearchProgress::Pending { expanded },
+			}
+		}
+	}
+
+	#[derive(Resource, Default)]
+	struct _Results(Vec<PathResult>);
+
+	impl _Results {
+		fn collect(mut results: EventReader<PathResult>, mut collected: ResMut<Self>) {
+			collected.0.extend(results.read().cloned());
+		}
+	}
+
+	fn setup<TMethod, TMode>() -> App
+	where
+		PathfindingPlugin<_Grid, TMethod, TMode>: Plugin,
+	{
+		let mut app = App::new();
+
+		app.add_plugins((
+			MinimalPlugins,
+			AssetPlugin::default(),
+			PathfindingPlugin::<_Grid, TMethod, TMode>::default(),
+		));
+		app.init_resource::<_Results>();
+		app.add_systems(Update, _Results::collect.after(PathfindingSystems));
+
+		app
+	}
+
+	fn request(requester: Entity, grid: Entity, end: Vec2) -> PathRequest {
+		PathRequest {
+			requester,
+			grid,
+			start: Vec2::new(1., 2.),
+			end,
+			options: default(),
+		}
+	}
+
+	#[test]
+	fn answer_path_request() {
+		let mut app = setup::<_Method, Async>();
+		let handle = app.world_mut().resource_mut::<Assets<_Grid>>().add(_Grid);
+		let grid = app.world_mut().spawn(GridContext::from_handle(handle)).id();
+		let request = PathRequest {
+			requester: grid,
+			grid,
+			start: Vec2::new(1., 2.),
+			end: Vec2::new(3., 4.),
+			options: default(),
+		};
+
+		app.update();
+		app.world_mut().send_event(request.clone());
+		while app.world().resource::<_Results>().0.is_empty() {
+			app.update();
+		}
+
+		assert_eq!(
+			vec![PathResult {
+				request,
+				path: vec![Vec2::new(1., 2.), Vec2::new(3., 4.)],
+			}],
+			app.world().resource::<_Results>().0
+		);
+	}
+
+	#[test]
+	fn answer_sliced_requests_over_frames_within_shared_budget() {
+		let mut app = setup::<_Method, Sliced>();
+		app.insert_resource(ExpansionBudget {
+			per_request: 50,
+			per_frame: 50,
+			..default()
+		});
+		let handle = app.world_mut().resource_mut::<Assets<_Grid>>().add(_Grid);
+		let grid = app.world_mut().spawn(GridContext::from_handle(handle)).id();
+		let a = app.world_mut().spawn_empty().id();
+		let b = app.world_mut().spawn_empty().id();
+
+		app.update();
+		app.world_mut()
+			.send_event(request(a, grid, Vec2::new(3., 4.)));
+		app.world_mut()
+			.send_event(request(b, grid, Vec2::new(5., 6.)));
+		let mut frames = vec![];
+		for _ in 0..4 {
+			app.update();
+			frames.push(
+				app.world()
+					.resource::<_Results>()
+					.0
+					.iter()
+					.map(|result| result.request.requester)
+					.collect::<Vec<_>>(),
+			);
+		}
+
+		assert_eq!(vec![vec![], vec![], vec![a], vec![a, b]], frames);
+	}
+
+	#[test]
+	fn draw_improving_bounds_of_anytime_search_over_frames() {
+		let mut app = setup::<AnytimeAStar, Sliced>();
+		app.insert_resource(ExpansionBudget {
+			per_request: 4,
+			per_frame: 4,
+			..default()
+		});
+		let grid = ComputeGrid {
+			min: ComputeGridNode::new(0, 0),
+			max: ComputeGridNode::new(6, 6),
+		};
+		let obstacles = Obstacles::from_nodes(
+			grid,
+			[(2, 2), (3, 4), (4, 6), (5, 1), (5, 3), (5, 4), (5, 5)]
+				.map(|(x, y)| ComputeGridNode::new(x, y)),
+		);
+		let handle = app.world_mut().resource_mut::<Assets<_Grid>>().add(_Grid);
+		let grid = app
+			.world_mut()
+			.spawn(GridContext {
+				handle,
+				grid,
+				obstacles,
+			})
+			.id();
+
+		app.update();
+		app.world_mut().send_event(PathRequest {
+			requester: grid,
+			grid,
+			start: Vec2::new(0., 0.),
+			end: Vec2::new(6., 6.),
+			options: PathOptions {
+				draw: true,
+				..default()
+			},
+		});
+		let mut bounds = vec![];
+		for _ in 0..100 {
+			app.update();
+			let bound = app
+				.world_mut()
+				.query_filtered::<&PathStats, Without<Despawn>>()
+				.iter(app.world())
+				.find_map(|stats| stats.bound);
+			if bound.is_some() && bounds.last() != Some(&bound) {
+				bounds.push(bound);
+			}
+		}
+
+		assert!(
+			bounds.len() > 1
+				&& bounds.windows(2).all(|pair| pair[0] > pair[1])
+				&& bounds.last() == Some(&Some(1.)),
+			"{:?}",
+			bounds
+		);
+	}
+}
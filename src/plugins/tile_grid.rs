@@ -0,0 +1,26 @@
+use super::editor::TileSystems;
+use crate::{components::grid_context::GridContext, traits::computable_grid::ComputableGrid};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Spawns one tile per cell for grids with a [`GridContext<TGrid>`], whenever their
+/// `TGrid` asset is added or modified.
+pub struct TileGridPlugin<TGrid>(PhantomData<TGrid>);
+
+impl<TGrid> Default for TileGridPlugin<TGrid> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<TGrid> Plugin for TileGridPlugin<TGrid>
+where
+	TGrid: Asset + ComputableGrid,
+{
+	fn build(&self, app: &mut App) {
+		app.add_systems(
+			Update,
+			GridContext::<TGrid>::spawn_tiles.in_set(TileSystems),
+		);
+	}
+}
@@ -1 +1,3 @@
+pub mod expansion_budget;
 pub mod mouse_world_position;
+pub mod path_exporter;
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Debug, PartialEq, Clone, Copy)]
+pub struct ExpansionBudget {
+	pub per_request: usize,
+	pub per_frame: usize,
+	/// After which an anytime search settles with its latest path
+	pub per_anytime_search: usize,
+}
+
+impl Default for ExpansionBudget {
+	fn default() -> Self {
+		Self {
+			per_request: 256,
+			per_frame: 2048,
+			per_anytime_search: 16384,
+		}
+	}
+}
@@ -209,3 +209,94 @@ mod tests {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod test_zoomed_camera {
+	use super::*;
+	use crate::{
+		components::player_camera::PlayerCamera,
+		test_tools::{primary_window, ComputeCameras},
+	};
+	use bevy::render::camera::camera_system;
+
+	fn setup(cursor: Vec2) -> App {
+		let mut app = App::new().compute_cameras(Update);
+		app.init_resource::<MouseWorldPosition>();
+		app.add_systems(
+			Update,
+			MouseWorldPosition::update_using::<PlayerCamera>
+				.after(camera_system::<OrthographicProjection>),
+		);
+		app.world_mut().spawn(primary_window(cursor));
+
+		app
+	}
+
+	fn camera(translation: Vec3, scale: f32) -> impl Bundle {
+		(
+			PlayerCamera,
+			OrthographicProjection {
+				scale,
+				..OrthographicProjection::default_2d()
+			},
+			Transform::from_translation(translation),
+			GlobalTransform::from_translation(translation),
+		)
+	}
+
+	fn mouse_position(app: &App) -> Option<Vec2> {
+		let MouseWorldPosition(position) = app.world().resource::<MouseWorldPosition>();
+		*position
+	}
+
+	#[test]
+	fn mouse_position_when_zoomed_out() {
+		let mut app = setup(Vec2::new(600., 200.));
+		app.world_mut().spawn(camera(Vec3::new(10., 20., 0.), 2.));
+
+		app.update();
+
+		assert_eq!(
+			Some(true),
+			mouse_position(&app).map(|p| p.abs_diff_eq(Vec2::new(410., 220.), 0.001)),
+			"{:?}",
+			mouse_position(&app)
+		);
+	}
+
+	#[test]
+	fn mouse_position_when_zoomed_in() {
+		let mut app = setup(Vec2::new(600., 200.));
+		app.world_mut().spawn(camera(Vec3::new(10., 20., 0.), 0.25));
+
+		app.update();
+
+		assert_eq!(
+			Some(true),
+			mouse_position(&app).map(|p| p.abs_diff_eq(Vec2::new(60., 45.), 0.001)),
+			"{:?}",
+			mouse_position(&app)
+		);
+	}
+
+	#[test]
+	fn mouse_position_after_zoom_changed() {
+		let mut app = setup(Vec2::new(600., 200.));
+		let camera = app.world_mut().spawn(camera(Vec3::ZERO, 1.)).id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(camera)
+			.get_mut::<OrthographicProjection>()
+			.unwrap()
+			.scale = 3.;
+		app.update();
+
+		assert_eq!(
+			Some(true),
+			mouse_position(&app).map(|p| p.abs_diff_eq(Vec2::new(600., 300.), 0.001)),
+			"{:?}",
+			mouse_position(&app)
+		);
+	}
+}
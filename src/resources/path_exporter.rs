@@ -0,0 +1,177 @@
+use crate::{
+	components::{computed_path::ComputedRoute, grid_context::GridContext, path_stats::PathStats},
+	dtos::path_export::PathExport,
+};
+use bevy::prelude::*;
+use std::{
+	path::PathBuf,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Resource, Debug, PartialEq, Clone)]
+pub struct PathExporter {
+	pub directory: PathBuf,
+}
+
+impl Default for PathExporter {
+	fn default() -> Self {
+		Self {
+			directory: PathBuf::from("exports"),
+		}
+	}
+}
+
+impl PathExporter {
+	pub const KEY: KeyCode = KeyCode::KeyE;
+
+	pub fn export_on_key<TGrid>(
+		exporter: Res<Self>,
+		keys: Res<ButtonInput<KeyCode>>,
+		paths: Query<(&Parent, &PathStats, &ComputedRoute)>,
+		contexts: Query<&GridContext<TGrid>>,
+	) where
+		TGrid: Asset,
+	{
+		if !keys.just_pressed(Self::KEY) {
+			return;
+		}
+
+		let timestamp = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map(|duration| duration.as_millis())
+			.unwrap_or_default();
+
+		for (parent, stats, route) in &paths {
+			let Ok(context) = contexts.get(parent.get()) else {
+				continue;
+			};
+			let Some(export) =
+				PathExport::new(&stats.method, context.grid, &context.obstacles, route)
+			else {
+				continue;
+			};
+			let file = exporter
+				.directory
+				.join(format!("path_{timestamp}_{}.json", parent.get().index()));
+
+			match export.write(&file) {
+				Ok(()) => info!("Exported path to {}", file.display()),
+				Err(error) => error!("{error}"),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		dtos::path_query::PathQuery,
+		new_handle,
+		test_tools::SingleThreaded,
+		traits::computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+	};
+	use bevy::utils::Duration;
+	use std::{env::temp_dir, fs};
+
+	#[derive(Asset, TypePath)]
+	struct _Grid;
+
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(2, 2),
+	};
+
+	fn setup(directory: &str) -> (App, PathBuf) {
+		let directory = temp_dir()
+			.join("project_zyheeda_pathfinding_path_exporter")
+			.join(directory);
+		let _ = fs::remove_dir_all(&directory);
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<ButtonInput<KeyCode>>();
+		app.insert_resource(PathExporter {
+			directory: directory.clone(),
+		});
+		app.add_systems(Update, PathExporter::export_on_key::<_Grid>);
+
+		(app, directory)
+	}
+
+	fn spawn_path(app: &mut App) {
+		app.world_mut()
+			.spawn(GridContext::<_Grid> {
+				handle: new_handle!(_Grid),
+				grid: GRID,
+				obstacles: Obstacles::from_nodes(GRID, [ComputeGridNode::new(1, 1)]),
+			})
+			.with_child((
+				PathStats {
+					method: "AStar".to_owned(),
+					length: 0.,
+					steps: 0,
+					nodes: 0,
+					turns: 0,
+					duration: Duration::ZERO,
+					expanded: None,
+					bound: None,
+				},
+				ComputedRoute {
+					route: vec![ComputeGridNode::new(0, 0), ComputeGridNode::new(2, 2)],
+					path: vec![
+						ComputeGridNode::new(0, 0),
+						ComputeGridNode::new(1, 0),
+						ComputeGridNode::new(2, 1),
+						ComputeGridNode::new(2, 2),
+					],
+				},
+			));
+	}
+
+	fn exported(directory: &PathBuf) -> Vec<PathExport> {
+		let Ok(entries) = fs::read_dir(directory) else {
+			return vec![];
+		};
+
+		entries
+			.filter_map(|entry| PathExport::read(&entry.ok()?.path()).ok())
+			.collect()
+	}
+
+	#[test]
+	fn export_on_key() {
+		let (mut app, directory) = setup("export_on_key");
+		spawn_path(&mut app);
+
+		app.world_mut()
+			.resource_mut::<ButtonInput<KeyCode>>()
+			.press(PathExporter::KEY);
+		app.update();
+
+		assert_eq!(
+			vec![PathExport {
+				query: PathQuery {
+					method: "AStar".to_owned(),
+					min: [0, 0],
+					max: [2, 2],
+					obstacles: vec![[1, 1]],
+					start: [0, 0],
+					end: [2, 2],
+					waypoints: vec![],
+				},
+				path: vec![[0, 0], [1, 0], [2, 1], [2, 2]],
+			}],
+			exported(&directory)
+		);
+	}
+
+	#[test]
+	fn do_not_export_without_key() {
+		let (mut app, directory) = setup("do_not_export_without_key");
+		spawn_path(&mut app);
+
+		app.update();
+
+		assert_eq!(vec![] as Vec<PathExport>, exported(&directory));
+	}
+}
@@ -1,6 +1,14 @@
 use bevy::{
 	ecs::schedule::{ExecutorKind, ScheduleLabel},
 	prelude::*,
+	render::camera::{camera_system, ManualTextureViews},
+	window::{
+		PrimaryWindow,
+		WindowCreated,
+		WindowResized,
+		WindowResolution,
+		WindowScaleFactorChanged,
+	},
 };
 
 #[macro_export]
@@ -52,3 +60,34 @@ impl SingleThreaded for App {
 		self
 	}
 }
+
+pub trait ComputeCameras {
+	/// Computes [`Camera`] projections like the render app would, so that
+	/// [`Camera::viewport_to_world`] can be used without a renderer.
+	fn compute_cameras(self, label: impl ScheduleLabel) -> Self;
+}
+
+impl ComputeCameras for App {
+	fn compute_cameras(mut self, label: impl ScheduleLabel) -> Self {
+		self.add_event::<WindowResized>()
+			.add_event::<WindowCreated>()
+			.add_event::<WindowScaleFactorChanged>()
+			.add_event::<AssetEvent<Image>>()
+			.init_resource::<Assets<Image>>()
+			.init_resource::<ManualTextureViews>()
+			.add_systems(label, camera_system::<OrthographicProjection>);
+
+		self
+	}
+}
+
+/// Primary window of 800x600 logical pixels with the cursor at `cursor`.
+pub fn primary_window(cursor: Vec2) -> (Window, PrimaryWindow) {
+	let mut window = Window {
+		resolution: WindowResolution::new(800., 600.),
+		..default()
+	};
+	window.set_cursor_position(Some(cursor));
+
+	(window, PrimaryWindow)
+}
@@ -1,12 +1,26 @@
+#[cfg(feature = "app")]
 pub mod asset_handle;
 pub mod computable_grid;
 pub mod compute_path;
+#[cfg(feature = "app")]
 pub mod concat;
+pub mod cost;
+#[cfg(feature = "app")]
 pub mod get_key;
+#[cfg(feature = "app")]
 pub mod get_mouse_ray;
+pub mod heuristic;
+#[cfg(feature = "app")]
 pub mod into_component;
+#[cfg(feature = "app")]
 pub mod is_point_hit;
+pub mod line_of_sight;
+#[cfg(feature = "app")]
 pub mod load_asset;
+#[cfg(feature = "app")]
 pub mod load_from;
+#[cfg(feature = "app")]
 pub mod parse;
+#[cfg(feature = "app")]
 pub mod set_value;
+pub mod space_time_constraints;
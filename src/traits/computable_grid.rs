@@ -1,3 +1,6 @@
+pub mod chunked;
+pub mod dense;
+
 use bevy::prelude::*;
 use std::ops::{Add, AddAssign, Sub};
 
@@ -24,6 +27,51 @@ pub struct ComputeGrid {
 	pub max: ComputeGridNode,
 }
 
+impl ComputeGrid {
+	fn width(&self) -> usize {
+		(self.max.x - self.min.x + 1).max(0) as usize
+	}
+
+	fn height(&self) -> usize {
+		(self.max.y - self.min.y + 1).max(0) as usize
+	}
+
+	/// Number of nodes within `min` and `max`, both inclusive.
+	pub fn len(&self) -> usize {
+		self.width() * self.height()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	pub fn contains(&self, ComputeGridNode { x, y }: ComputeGridNode) -> bool {
+		x >= self.min.x && x <= self.max.x && y >= self.min.y && y <= self.max.y
+	}
+
+	/// Row major index of `node`, `None` when `node` is outside the grid.
+	pub fn index(&self, node: ComputeGridNode) -> Option<usize> {
+		if !self.contains(node) {
+			return None;
+		}
+
+		let x = (node.x - self.min.x) as usize;
+		let y = (node.y - self.min.y) as usize;
+
+		Some(y * self.width() + x)
+	}
+
+	/// Inverse of [`ComputeGrid::index`].
+	pub fn node(&self, index: usize) -> ComputeGridNode {
+		let width = self.width();
+
+		ComputeGridNode::new(
+			self.min.x + (index % width) as i32,
+			self.min.y + (index / width) as i32,
+		)
+	}
+}
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Clone, Copy)]
 pub struct ComputeGridNode {
 	pub x: i32,
@@ -0,0 +1,248 @@
+use super::{dense::Obstacles, ComputeGrid, ComputeGridNode};
+use std::collections::{HashMap, HashSet};
+
+/// Obstacles of an unbounded grid, split into square chunks of `size` by `size` nodes.
+///
+/// Obstacles of unloaded chunks are kept, so they are restored when the chunk is
+/// loaded again. Only loaded chunks are searchable, see [`ChunkedObstacles::obstacles`].
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct ChunkedObstacles {
+	size: u32,
+	chunks: HashMap<ComputeGridNode, Obstacles>,
+	loaded: HashSet<ComputeGridNode>,
+}
+
+impl ChunkedObstacles {
+	pub fn new(size: u32) -> Self {
+		Self {
+			size,
+			..Default::default()
+		}
+	}
+
+	pub fn size(&self) -> u32 {
+		self.size
+	}
+
+	fn width(&self) -> i32 {
+		self.size.max(1) as i32
+	}
+
+	/// Chunk coordinate containing `node`.
+	pub fn chunk(&self, node: ComputeGridNode) -> ComputeGridNode {
+		ComputeGridNode::new(
+			node.x.div_euclid(self.width()),
+			node.y.div_euclid(self.width()),
+		)
+	}
+
+	/// Nodes covered by `chunk`.
+	pub fn chunk_grid(&self, chunk: ComputeGridNode) -> ComputeGrid {
+		let min = ComputeGridNode::new(chunk.x * self.width(), chunk.y * self.width());
+
+		ComputeGrid {
+			min,
+			max: min + ComputeGridNode::new(self.width() - 1, self.width() - 1),
+		}
+	}
+
+	/// Returns `false`, when `chunk` was already loaded.
+	pub fn load(&mut self, chunk: ComputeGridNode) -> bool {
+		self.loaded.insert(chunk)
+	}
+
+	/// Returns `false`, when `chunk` was not loaded.
+	pub fn unload(&mut self, chunk: &ComputeGridNode) -> bool {
+		self.loaded.remove(chunk)
+	}
+
+	pub fn is_loaded(&self, chunk: &ComputeGridNode) -> bool {
+		self.loaded.contains(chunk)
+	}
+
+	pub fn loaded(&self) -> impl Iterator<Item = ComputeGridNode> + '_ {
+		self.loaded.iter().copied()
+	}
+
+	/// Returns `false`, when `node` was already contained.
+	pub fn insert(&mut self, node: ComputeGridNode) -> bool {
+		let chunk = self.chunk(node);
+		let grid = self.chunk_grid(chunk);
+
+		self.chunks
+			.entry(chunk)
+			.or_insert_with(|| Obstacles::new(grid))
+			.insert(node)
+	}
+
+	/// Returns `false`, when `node` was not contained.
+	pub fn remove(&mut self, node: &ComputeGridNode) -> bool {
+		let Some(obstacles) = self.chunks.get_mut(&self.chunk(*node)) else {
+			return false;
+		};
+
+		obstacles.remove(node)
+	}
+
+	pub fn contains(&self, node: &ComputeGridNode) -> bool {
+		let Some(obstacles) = self.chunks.get(&self.chunk(*node)) else {
+			return false;
+		};
+
+		obstacles.contains(node)
+	}
+
+	/// Smallest grid containing all loaded chunks.
+	pub fn grid(&self) -> ComputeGrid {
+		let mut chunks = self.loaded.iter();
+		let Some(first) = chunks.next() else {
+			return ComputeGrid::default();
+		};
+		let (min, max) = chunks.fold((*first, *first), |(min, max), chunk| {
+			(
+				ComputeGridNode::new(min.x.min(chunk.x), min.y.min(chunk.y)),
+				ComputeGridNode::new(max.x.max(chunk.x), max.y.max(chunk.y)),
+			)
+		});
+
+		ComputeGrid {
+			min: self.chunk_grid(min).min,
+			max: self.chunk_grid(max).max,
+		}
+	}
+
+	/// Obstacles of all loaded chunks within [`ChunkedObstacles::grid`], so that
+	/// searches can cross chunk boundaries. Nodes of chunks, that are not loaded,
+	/// are blocked.
+	pub fn obstacles(&self) -> Obstacles {
+		let grid = self.grid();
+		let mut obstacles = Obstacles::new(grid);
+
+		if self.loaded.is_empty() {
+			obstacles.insert(grid.min);
+			return obstacles;
+		}
+
+		let min = self.chunk(grid.min);
+		let max = self.chunk(grid.max);
+
+		for x in min.x..=max.x {
+			for y in min.y..=max.y {
+				let chunk = ComputeGridNode::new(x, y);
+				let blocked = match self.is_loaded(&chunk) {
+					true => self.chunk_obstacles(chunk),
+					false => self.chunk_nodes(chunk),
+				};
+
+				for node in blocked {
+					obstacles.insert(node);
+				}
+			}
+		}
+
+		obstacles
+	}
+
+	fn chunk_obstacles(&self, chunk: ComputeGridNode) -> Vec<ComputeGridNode> {
+		match self.chunks.get(&chunk) {
+			Some(obstacles) => obstacles.iter().collect(),
+			None => vec![],
+		}
+	}
+
+	fn chunk_nodes(&self, chunk: ComputeGridNode) -> Vec<ComputeGridNode> {
+		let ComputeGrid { min, max } = self.chunk_grid(chunk);
+
+		(min.x..=max.x)
+			.flat_map(|x| (min.y..=max.y).map(move |y| ComputeGridNode::new(x, y)))
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		components::compute_path_method::a_star::AStar,
+		traits::compute_path::{ComputePath, NewComputer},
+	};
+
+	#[test]
+	fn chunk_of_negative_nodes() {
+		let chunks = ChunkedObstacles::new(4);
+
+		assert_eq!(
+			[ComputeGridNode::new(-1, 0), ComputeGridNode::new(-2, -1)],
+			[
+				chunks.chunk(ComputeGridNode::new(-1, 3)),
+				chunks.chunk(ComputeGridNode::new(-5, -4)),
+			]
+		);
+	}
+
+	#[test]
+	fn grid_of_loaded_chunks() {
+		let mut chunks = ChunkedObstacles::new(4);
+		chunks.load(ComputeGridNode::new(-1, 0));
+		chunks.load(ComputeGridNode::new(1, 2));
+
+		assert_eq!(
+			ComputeGrid {
+				min: ComputeGridNode::new(-4, 0),
+				max: ComputeGridNode::new(7, 11),
+			},
+			chunks.grid()
+		);
+	}
+
+	#[test]
+	fn keep_obstacles_of_unloaded_chunks() {
+		let mut chunks = ChunkedObstacles::new(4);
+		chunks.load(ComputeGridNode::new(0, 0));
+		chunks.insert(ComputeGridNode::new(1, 2));
+
+		chunks.unload(&ComputeGridNode::new(0, 0));
+		chunks.load(ComputeGridNode::new(0, 0));
+
+		assert!(chunks.obstacles().contains(&ComputeGridNode::new(1, 2)));
+	}
+
+	#[test]
+	fn block_nodes_of_chunks_not_loaded() {
+		let mut chunks = ChunkedObstacles::new(2);
+		chunks.load(ComputeGridNode::new(0, 0));
+		chunks.load(ComputeGridNode::new(1, 1));
+
+		let obstacles = chunks.obstacles();
+
+		assert_eq!(
+			vec![
+				ComputeGridNode::new(2, 0),
+				ComputeGridNode::new(3, 0),
+				ComputeGridNode::new(2, 1),
+				ComputeGridNode::new(3, 1),
+				ComputeGridNode::new(0, 2),
+				ComputeGridNode::new(1, 2),
+				ComputeGridNode::new(0, 3),
+				ComputeGridNode::new(1, 3),
+			],
+			obstacles.iter().collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn search_across_chunk_boundaries() {
+		let mut chunks = ChunkedObstacles::new(2);
+		chunks.load(ComputeGridNode::new(-1, 0));
+		chunks.load(ComputeGridNode::new(0, 0));
+		chunks.insert(ComputeGridNode::new(-1, 0));
+		let a_star = <AStar>::new(chunks.grid(), chunks.obstacles());
+
+		let path = a_star.path(ComputeGridNode::new(-2, 0), ComputeGridNode::new(1, 0));
+
+		assert_eq!(
+			(6, Some(&ComputeGridNode::new(1, 0))),
+			(path.len(), path.last())
+		);
+	}
+}
@@ -0,0 +1,193 @@
+use super::{ComputeGrid, ComputeGridNode};
+
+/// Blocked nodes of a [`ComputeGrid`], stored as one bit per node.
+///
+/// Nodes outside the grid are never contained and cannot be inserted.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct Obstacles {
+	grid: ComputeGrid,
+	bits: Vec<u64>,
+}
+
+impl Obstacles {
+	pub fn new(grid: ComputeGrid) -> Self {
+		Self {
+			grid,
+			bits: vec![0; grid.len().div_ceil(64)],
+		}
+	}
+
+	pub fn grid(&self) -> ComputeGrid {
+		self.grid
+	}
+
+	/// Returns `false`, when `node` was already contained or is outside the grid.
+	pub fn insert(&mut self, node: ComputeGridNode) -> bool {
+		let Some(index) = self.grid.index(node) else {
+			return false;
+		};
+		let (word, bit) = (index / 64, 1 << (index % 64));
+		let inserted = self.bits[word] & bit == 0;
+
+		self.bits[word] |= bit;
+		inserted
+	}
+
+	/// Returns `false`, when `node` was not contained.
+	pub fn remove(&mut self, node: &ComputeGridNode) -> bool {
+		let Some(index) = self.grid.index(*node) else {
+			return false;
+		};
+		let (word, bit) = (index / 64, 1 << (index % 64));
+		let removed = self.bits[word] & bit != 0;
+
+		self.bits[word] &= !bit;
+		removed
+	}
+
+	pub fn contains(&self, node: &ComputeGridNode) -> bool {
+		let Some(index) = self.grid.index(*node) else {
+			return false;
+		};
+
+		self.bits[index / 64] & (1 << (index % 64)) != 0
+	}
+
+	pub fn clear(&mut self) {
+		self.bits.fill(0);
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = ComputeGridNode> + '_ {
+		(0..self.grid.len())
+			.filter(|index| self.bits[index / 64] & (1 << (index % 64)) != 0)
+			.map(|index| self.grid.node(index))
+	}
+
+	pub fn from_nodes<T>(grid: ComputeGrid, nodes: T) -> Self
+	where
+		T: IntoIterator<Item = ComputeGridNode>,
+	{
+		let mut obstacles = Self::new(grid);
+
+		for node in nodes {
+			obstacles.insert(node);
+		}
+
+		obstacles
+	}
+}
+
+/// A value per node of a [`ComputeGrid`], stored in one flat array.
+///
+/// Reads outside the grid yield `None` and writes outside the grid are ignored.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct NodeMap<T> {
+	grid: ComputeGrid,
+	values: Vec<T>,
+}
+
+impl<T> NodeMap<T>
+where
+	T: Clone,
+{
+	pub fn new(grid: ComputeGrid, value: T) -> Self {
+		Self {
+			grid,
+			values: vec![value; grid.len()],
+		}
+	}
+
+	pub fn get(&self, node: &ComputeGridNode) -> Option<&T> {
+		self.values.get(self.grid.index(*node)?)
+	}
+
+	pub fn insert(&mut self, node: ComputeGridNode, value: T) {
+		let Some(slot) = self.grid.index(node).and_then(|i| self.values.get_mut(i)) else {
+			return;
+		};
+
+		*slot = value;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn grid() -> ComputeGrid {
+		ComputeGrid {
+			min: ComputeGridNode::new(-2, -1),
+			max: ComputeGridNode::new(10, 7),
+		}
+	}
+
+	#[test]
+	fn obstacles_contain_inserted() {
+		let obstacles = Obstacles::from_nodes(
+			grid(),
+			[ComputeGridNode::new(-2, -1), ComputeGridNode::new(10, 7)],
+		);
+
+		assert_eq!(
+			(true, true, false),
+			(
+				obstacles.contains(&ComputeGridNode::new(-2, -1)),
+				obstacles.contains(&ComputeGridNode::new(10, 7)),
+				obstacles.contains(&ComputeGridNode::new(0, 0)),
+			)
+		);
+	}
+
+	#[test]
+	fn obstacles_remove() {
+		let mut obstacles = Obstacles::from_nodes(grid(), [ComputeGridNode::new(3, 4)]);
+
+		let removed = obstacles.remove(&ComputeGridNode::new(3, 4));
+
+		assert_eq!(
+			(true, false),
+			(removed, obstacles.contains(&ComputeGridNode::new(3, 4)))
+		);
+	}
+
+	#[test]
+	fn obstacles_ignore_nodes_outside_grid() {
+		let mut obstacles = Obstacles::new(grid());
+
+		let inserted = obstacles.insert(ComputeGridNode::new(11, 0));
+
+		assert_eq!(
+			(false, false),
+			(inserted, obstacles.contains(&ComputeGridNode::new(11, 0)))
+		);
+	}
+
+	#[test]
+	fn obstacles_iter() {
+		let obstacles = Obstacles::from_nodes(
+			grid(),
+			[ComputeGridNode::new(4, 5), ComputeGridNode::new(-1, 0)],
+		);
+
+		assert_eq!(
+			vec![ComputeGridNode::new(-1, 0), ComputeGridNode::new(4, 5)],
+			obstacles.iter().collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn node_map_get_inserted() {
+		let mut map = NodeMap::new(grid(), 0);
+
+		map.insert(ComputeGridNode::new(3, 4), 42);
+
+		assert_eq!(
+			(Some(&42), Some(&0), None),
+			(
+				map.get(&ComputeGridNode::new(3, 4)),
+				map.get(&ComputeGridNode::new(4, 3)),
+				map.get(&ComputeGridNode::new(3, 8)),
+			)
+		);
+	}
+}
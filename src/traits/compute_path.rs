@@ -1,11 +1,50 @@
-use super::computable_grid::{ComputeGrid, ComputeGridNode};
-use std::collections::HashSet;
+pub mod validity;
+
+use super::computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode};
 
 pub trait NewComputer {
-	fn new(grid: ComputeGrid, obstacles: HashSet<ComputeGridNode>) -> Self;
+	fn new(grid: ComputeGrid, obstacles: Obstacles) -> Self;
 }
 
 pub trait ComputePath {
 	fn draw_connections(&self) -> bool;
 	fn path(&self, start: ComputeGridNode, end: ComputeGridNode) -> Vec<ComputeGridNode>;
+
+	fn path_with_expansions(
+		&self,
+		start: ComputeGridNode,
+		end: ComputeGridNode,
+	) -> (Option<usize>, Vec<ComputeGridNode>) {
+		(None, self.path(start, end))
+	}
+
+	/// Factor, by which returned paths may cost more than the shortest path
+	fn suboptimality_bound(&self) -> Option<f32> {
+		None
+	}
+}
+
+pub trait ComputePathSliced {
+	type TSearch: Sync + Send + 'static;
+
+	fn start_search(&self, start: ComputeGridNode, end: ComputeGridNode) -> Self::TSearch;
+
+	fn continue_search(&self, search: &mut Self::TSearch, budget: usize) -> SearchProgress;
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum SearchProgress {
+	Pending {
+		expanded: usize,
+	},
+	/// Costs at most `bound` times as much as the shortest path, still improving
+	Improved {
+		expanded: usize,
+		path: Vec<ComputeGridNode>,
+		bound: f32,
+	},
+	Done {
+		expanded: usize,
+		path: Vec<ComputeGridNode>,
+	},
 }
@@ -0,0 +1,295 @@
+use crate::{
+	components::compute_path_method::straight_line_wide::LineWide,
+	traits::computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+};
+
+/// Moves allowed between consecutive path nodes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Moves {
+	/// Horizontal and vertical steps onto neighboring nodes
+	Straight,
+	/// Steps onto any of the eight neighboring nodes
+	Adjacent,
+	/// Lines to any node, as long as all nodes touched by [`LineWide`] are free
+	LineOfSight,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InvalidPath {
+	WrongStart(ComputeGridNode),
+	WrongEnd(ComputeGridNode),
+	OutsideGrid(ComputeGridNode),
+	Blocked(ComputeGridNode),
+	IllegalMove(ComputeGridNode, ComputeGridNode),
+}
+
+/// Checks paths of a [`ComputePath`](super::ComputePath) method against its grid and
+/// obstacles.
+#[derive(Debug, Clone)]
+pub struct PathValidity<'a> {
+	pub grid: ComputeGrid,
+	pub obstacles: &'a Obstacles,
+	pub moves: Moves,
+}
+
+impl PathValidity<'_> {
+	/// Empty paths are valid, because they mark `end` as unreachable.
+	pub fn check(
+		&self,
+		start: ComputeGridNode,
+		end: ComputeGridNode,
+		path: &[ComputeGridNode],
+	) -> Result<(), InvalidPath> {
+		let (Some(first), Some(last)) = (path.first(), path.last()) else {
+			return Ok(());
+		};
+
+		if first != &start {
+			return Err(InvalidPath::WrongStart(*first));
+		}
+		if last != &end {
+			return Err(InvalidPath::WrongEnd(*last));
+		}
+
+		for node in path {
+			self.check_node(*node)?;
+		}
+
+		for pair in path.windows(2) {
+			let [a, b] = pair else {
+				continue;
+			};
+			if !self.is_legal_move(*a, *b) {
+				return Err(InvalidPath::IllegalMove(*a, *b));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn check_node(&self, node: ComputeGridNode) -> Result<(), InvalidPath> {
+		if !self.grid.contains(node) {
+			return Err(InvalidPath::OutsideGrid(node));
+		}
+		if self.obstacles.contains(&node) {
+			return Err(InvalidPath::Blocked(node));
+		}
+
+		Ok(())
+	}
+
+	/// Staying on the same node is no move.
+	pub fn is_legal_move(&self, a: ComputeGridNode, b: ComputeGridNode) -> bool {
+		let delta = b - a;
+		let (dx, dy) = (delta.x.abs(), delta.y.abs());
+
+		match self.moves {
+			Moves::Straight => dx + dy == 1,
+			Moves::Adjacent => dx.max(dy) == 1,
+			Moves::LineOfSight => a != b && self.is_line_of_sight(a, b),
+		}
+	}
+
+	/// Whether all nodes touched by the [`LineWide`] from `a` to `b` are free.
+	pub fn is_line_of_sight(&self, a: ComputeGridNode, b: ComputeGridNode) -> bool {
+		LineWide::new(a, b).all(|node| self.check_node(node).is_ok())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(3, 3),
+	};
+
+	fn nodes(nodes: &[(i32, i32)]) -> Vec<ComputeGridNode> {
+		nodes
+			.iter()
+			.map(|(x, y)| ComputeGridNode::new(*x, *y))
+			.collect()
+	}
+
+	fn validity(obstacles: &Obstacles, moves: Moves) -> PathValidity<'_> {
+		PathValidity {
+			grid: GRID,
+			obstacles,
+			moves,
+		}
+	}
+
+	#[test]
+	fn empty_path_is_valid() {
+		let obstacles = Obstacles::new(GRID);
+
+		let result = validity(&obstacles, Moves::Straight).check(
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(3, 3),
+			&[],
+		);
+
+		assert_eq!(Ok(()), result);
+	}
+
+	#[test]
+	fn straight_moves() {
+		let obstacles = Obstacles::new(GRID);
+
+		let result = validity(&obstacles, Moves::Straight).check(
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(1, 1),
+			&nodes(&[(0, 0), (0, 1), (1, 1)]),
+		);
+
+		assert_eq!(Ok(()), result);
+	}
+
+	#[test]
+	fn wrong_start() {
+		let obstacles = Obstacles::new(GRID);
+
+		let result = validity(&obstacles, Moves::Straight).check(
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(1, 1),
+			&nodes(&[(0, 1), (1, 1)]),
+		);
+
+		assert_eq!(
+			Err(InvalidPath::WrongStart(ComputeGridNode::new(0, 1))),
+			result
+		);
+	}
+
+	#[test]
+	fn wrong_end() {
+		let obstacles = Obstacles::new(GRID);
+
+		let result = validity(&obstacles, Moves::Straight).check(
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(1, 1),
+			&nodes(&[(0, 0), (0, 1)]),
+		);
+
+		assert_eq!(
+			Err(InvalidPath::WrongEnd(ComputeGridNode::new(0, 1))),
+			result
+		);
+	}
+
+	#[test]
+	fn outside_grid() {
+		let obstacles = Obstacles::new(GRID);
+
+		let result = validity(&obstacles, Moves::Straight).check(
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(0, 0),
+			&nodes(&[(0, 0), (-1, 0), (0, 0)]),
+		);
+
+		assert_eq!(
+			Err(InvalidPath::OutsideGrid(ComputeGridNode::new(-1, 0))),
+			result
+		);
+	}
+
+	#[test]
+	fn blocked() {
+		let obstacles = Obstacles::from_nodes(GRID, [ComputeGridNode::new(0, 1)]);
+
+		let result = validity(&obstacles, Moves::Straight).check(
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(1, 1),
+			&nodes(&[(0, 0), (0, 1), (1, 1)]),
+		);
+
+		assert_eq!(
+			Err(InvalidPath::Blocked(ComputeGridNode::new(0, 1))),
+			result
+		);
+	}
+
+	#[test]
+	fn diagonal_move_is_not_straight() {
+		let obstacles = Obstacles::new(GRID);
+
+		let result = validity(&obstacles, Moves::Straight).check(
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(1, 1),
+			&nodes(&[(0, 0), (1, 1)]),
+		);
+
+		assert_eq!(
+			Err(InvalidPath::IllegalMove(
+				ComputeGridNode::new(0, 0),
+				ComputeGridNode::new(1, 1)
+			)),
+			result
+		);
+	}
+
+	#[test]
+	fn diagonal_move_is_adjacent() {
+		let obstacles = Obstacles::new(GRID);
+
+		let result = validity(&obstacles, Moves::Adjacent).check(
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(1, 1),
+			&nodes(&[(0, 0), (1, 1)]),
+		);
+
+		assert_eq!(Ok(()), result);
+	}
+
+	#[test]
+	fn staying_is_no_move() {
+		let obstacles = Obstacles::new(GRID);
+
+		let result = validity(&obstacles, Moves::LineOfSight).check(
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(3, 0),
+			&nodes(&[(0, 0), (0, 0), (3, 0)]),
+		);
+
+		assert_eq!(
+			Err(InvalidPath::IllegalMove(
+				ComputeGridNode::new(0, 0),
+				ComputeGridNode::new(0, 0)
+			)),
+			result
+		);
+	}
+
+	#[test]
+	fn line_of_sight() {
+		let obstacles = Obstacles::from_nodes(GRID, [ComputeGridNode::new(1, 1)]);
+
+		let result = validity(&obstacles, Moves::LineOfSight).check(
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(3, 2),
+			&nodes(&[(0, 0), (3, 0), (3, 2)]),
+		);
+
+		assert_eq!(Ok(()), result);
+	}
+
+	#[test]
+	fn line_of_sight_blocked() {
+		let obstacles = Obstacles::from_nodes(GRID, [ComputeGridNode::new(1, 1)]);
+
+		let result = validity(&obstacles, Moves::LineOfSight).check(
+			ComputeGridNode::new(0, 0),
+			ComputeGridNode::new(3, 2),
+			&nodes(&[(0, 0), (3, 2)]),
+		);
+
+		assert_eq!(
+			Err(InvalidPath::IllegalMove(
+				ComputeGridNode::new(0, 0),
+				ComputeGridNode::new(3, 2)
+			)),
+			result
+		);
+	}
+}
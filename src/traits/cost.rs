@@ -0,0 +1,214 @@
+use std::{
+	cmp::Ordering,
+	fmt::Debug,
+	ops::{Add, Neg},
+};
+
+pub trait Cost:
+	Copy + PartialOrd + Add<Output = Self> + Neg<Output = Self> + Debug + Send + Sync + 'static
+{
+	const ZERO: Self;
+	const INFINITY: Self;
+	const SQRT_2: Self;
+
+	fn from_steps(steps: u32) -> Self;
+
+	fn times(self, factor: u32) -> Self;
+
+	fn scale(self, factor: f32) -> Self;
+
+	/// Never above the exact length
+	fn hypot(d_x: u32, d_y: u32) -> Self;
+
+	fn total_cmp(&self, other: &Self) -> Ordering;
+
+	fn to_f32(self) -> f32;
+}
+
+impl Cost for f32 {
+	const ZERO: Self = 0.;
+	const INFINITY: Self = f32::INFINITY;
+	const SQRT_2: Self = std::f32::consts::SQRT_2;
+
+	fn from_steps(steps: u32) -> Self {
+		steps as f32
+	}
+
+	fn times(self, factor: u32) -> Self {
+		self * factor as f32
+	}
+
+	fn scale(self, factor: f32) -> Self {
+		self * factor
+	}
+
+	/// Unlike [`f32::hypot`], independent of the platform's math library
+	fn hypot(d_x: u32, d_y: u32) -> Self {
+		(((d_x as u64).pow(2) + (d_y as u64).pow(2)) as f32).sqrt()
+	}
+
+	fn total_cmp(&self, other: &Self) -> Ordering {
+		f32::total_cmp(self, other)
+	}
+
+	fn to_f32(self) -> f32 {
+		self
+	}
+}
+
+/// Integer arithmetic only, saturating instead of overflowing
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Clone, Copy, Hash)]
+pub struct Fixed(i64);
+
+impl Fixed {
+	pub const FRACTION_BITS: u32 = 16;
+	pub const ONE: Self = Self(1 << Self::FRACTION_BITS);
+
+	pub const fn from_bits(bits: i64) -> Self {
+		Self(bits)
+	}
+
+	pub const fn to_bits(self) -> i64 {
+		self.0
+	}
+}
+
+impl Add for Fixed {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		Self(self.0.saturating_add(rhs.0))
+	}
+}
+
+impl Neg for Fixed {
+	type Output = Self;
+
+	fn neg(self) -> Self {
+		Self(self.0.saturating_neg())
+	}
+}
+
+impl Cost for Fixed {
+	const ZERO: Self = Self(0);
+	const INFINITY: Self = Self(i64::MAX);
+	/// √2 rounded down, like [`Fixed::hypot`]
+	const SQRT_2: Self = Self(92_681);
+
+	fn from_steps(steps: u32) -> Self {
+		Self::ONE.times(steps)
+	}
+
+	fn times(self, factor: u32) -> Self {
+		Self(self.0.saturating_mul(factor as i64))
+	}
+
+	/// Multiplies with the integer mantissa and exponent of `factor`, rounding down
+	fn scale(self, factor: f32) -> Self {
+		let bits = factor.to_bits();
+		let (mantissa, exponent) = match (bits >> 23) & 0xff {
+			0 => (bits & 0x7f_ffff, -149),
+			0xff => return Self::INFINITY,
+			biased => ((bits & 0x7f_ffff) | 0x80_0000, biased as i32 - 150),
+		};
+		let sign = if bits >> 31 == 1 { -1 } else { 1 };
+		let product = self.0 as i128 * mantissa as i128 * sign;
+		let scaled = match exponent {
+			..0 => product >> (-exponent).min(127),
+			0.. => product
+				.checked_shl(exponent as u32)
+				.filter(|_| product.unsigned_abs().leading_zeros() > exponent as u32)
+				.unwrap_or(product.signum() * i128::MAX),
+		};
+
+		Self(scaled.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+	}
+
+	fn hypot(d_x: u32, d_y: u32) -> Self {
+		let squared = (d_x as u128).pow(2) + (d_y as u128).pow(2);
+		let length = (squared << (2 * Self::FRACTION_BITS)).isqrt();
+
+		Self(i64::try_from(length).unwrap_or(i64::MAX))
+	}
+
+	fn total_cmp(&self, other: &Self) -> Ordering {
+		self.cmp(other)
+	}
+
+	fn to_f32(self) -> f32 {
+		self.0 as f32 / Self::ONE.0 as f32
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fixed_steps() {
+		assert_eq!(Fixed::from_bits(3 << 16), Fixed::from_steps(3));
+	}
+
+	#[test]
+	fn fixed_sqrt_2_is_rounded_down() {
+		let bits = std::f64::consts::SQRT_2 * Fixed::ONE.to_bits() as f64;
+
+		assert_eq!(bits.floor() as i64, Fixed::SQRT_2.to_bits());
+	}
+
+	#[test]
+	fn fixed_hypot() {
+		assert_eq!(
+			(Fixed::from_steps(5), Fixed::from_bits(92_681)),
+			(Fixed::hypot(3, 4), Fixed::hypot(1, 1))
+		);
+	}
+
+	#[test]
+	fn fixed_saturates() {
+		assert_eq!(Fixed::INFINITY, Fixed::INFINITY + Fixed::ONE);
+	}
+
+	#[test]
+	fn fixed_scale() {
+		assert_eq!(Fixed::from_bits(98_304), Fixed::ONE.scale(1.5));
+	}
+
+	#[test]
+	fn fixed_scale_rounds_down() {
+		assert_eq!(
+			(Fixed::from_bits(1), Fixed::from_bits(-2)),
+			(
+				Fixed::from_bits(1).scale(1.5),
+				Fixed::from_bits(-1).scale(1.5)
+			)
+		);
+	}
+
+	#[test]
+	fn fixed_scale_saturates() {
+		assert_eq!(
+			(Fixed::INFINITY, Fixed::INFINITY),
+			(Fixed::ONE.scale(f32::MAX), Fixed::ONE.scale(f32::INFINITY))
+		);
+	}
+
+	#[test]
+	fn fixed_scale_saturates_on_large_shifts() {
+		let below_2_pow_64 = f32::from_bits(2f32.powi(64).to_bits() - 1);
+
+		assert_eq!(
+			(Fixed::INFINITY, Fixed::INFINITY, Fixed::from_bits(i64::MIN)),
+			(
+				Fixed::INFINITY.scale(2f32.powi(63)),
+				Fixed::INFINITY.scale(below_2_pow_64),
+				Fixed::from_bits(i64::MIN).scale(below_2_pow_64)
+			)
+		);
+	}
+
+	#[test]
+	fn fixed_to_f32() {
+		assert_eq!(2.5, Fixed::from_bits(163_840).to_f32());
+	}
+}
@@ -0,0 +1,220 @@
+pub mod landmarks;
+
+use super::{
+	computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+	cost::Cost,
+};
+
+pub trait Heuristic<TCost = f32> {
+	fn estimate(&self, node: ComputeGridNode, end: ComputeGridNode) -> TCost;
+}
+
+impl<T, TCost> Heuristic<TCost> for T
+where
+	T: Fn(ComputeGridNode, ComputeGridNode) -> TCost,
+{
+	fn estimate(&self, node: ComputeGridNode, end: ComputeGridNode) -> TCost {
+		self(node, end)
+	}
+}
+
+/// Called whenever a method is instantiated for a new grid context
+pub trait NewHeuristic {
+	fn new(grid: ComputeGrid, obstacles: &Obstacles) -> Self;
+}
+
+/// Computed from node positions alone, thus not bound to horizontal and vertical steps
+pub trait GridDistance {}
+
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+pub struct Manhattan;
+
+impl Manhattan {
+	pub fn distance<TCost>(a: ComputeGridNode, b: ComputeGridNode) -> TCost
+	where
+		TCost: Cost,
+	{
+		TCost::from_steps(a.x.abs_diff(b.x) + a.y.abs_diff(b.y))
+	}
+}
+
+impl<TCost> Heuristic<TCost> for Manhattan
+where
+	TCost: Cost,
+{
+	fn estimate(&self, node: ComputeGridNode, end: ComputeGridNode) -> TCost {
+		Self::distance(node, end)
+	}
+}
+
+impl NewHeuristic for Manhattan {
+	fn new(_: ComputeGrid, _: &Obstacles) -> Self {
+		Self
+	}
+}
+
+impl GridDistance for Manhattan {}
+
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+pub struct Octile;
+
+impl Octile {
+	pub fn distance<TCost>(a: ComputeGridNode, b: ComputeGridNode) -> TCost
+	where
+		TCost: Cost,
+	{
+		let d_x = a.x.abs_diff(b.x);
+		let d_y = a.y.abs_diff(b.y);
+
+		TCost::SQRT_2.times(d_x.min(d_y)) + TCost::from_steps(d_x.abs_diff(d_y))
+	}
+}
+
+impl<TCost> Heuristic<TCost> for Octile
+where
+	TCost: Cost,
+{
+	fn estimate(&self, node: ComputeGridNode, end: ComputeGridNode) -> TCost {
+		Self::distance(node, end)
+	}
+}
+
+impl NewHeuristic for Octile {
+	fn new(_: ComputeGrid, _: &Obstacles) -> Self {
+		Self
+	}
+}
+
+impl GridDistance for Octile {}
+
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+pub struct Euclidean;
+
+impl<TCost> Heuristic<TCost> for Euclidean
+where
+	TCost: Cost,
+{
+	fn estimate(&self, node: ComputeGridNode, end: ComputeGridNode) -> TCost {
+		TCost::hypot(node.x.abs_diff(end.x), node.y.abs_diff(end.y))
+	}
+}
+
+impl NewHeuristic for Euclidean {
+	fn new(_: ComputeGrid, _: &Obstacles) -> Self {
+		Self
+	}
+}
+
+impl GridDistance for Euclidean {}
+
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+pub struct Chebyshev;
+
+impl<TCost> Heuristic<TCost> for Chebyshev
+where
+	TCost: Cost,
+{
+	fn estimate(&self, node: ComputeGridNode, end: ComputeGridNode) -> TCost {
+		TCost::from_steps(node.x.abs_diff(end.x).max(node.y.abs_diff(end.y)))
+	}
+}
+
+impl NewHeuristic for Chebyshev {
+	fn new(_: ComputeGrid, _: &Obstacles) -> Self {
+		Self
+	}
+}
+
+impl GridDistance for Chebyshev {}
+
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+pub struct Zero;
+
+impl<TCost> Heuristic<TCost> for Zero
+where
+	TCost: Cost,
+{
+	fn estimate(&self, _: ComputeGridNode, _: ComputeGridNode) -> TCost {
+		TCost::ZERO
+	}
+}
+
+impl NewHeuristic for Zero {
+	fn new(_: ComputeGrid, _: &Obstacles) -> Self {
+		Self
+	}
+}
+
+impl GridDistance for Zero {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::traits::cost::Fixed;
+
+	const A: ComputeGridNode = ComputeGridNode::new(1, 2);
+	const B: ComputeGridNode = ComputeGridNode::new(4, -2);
+
+	#[test]
+	fn manhattan() {
+		let estimate: f32 = Manhattan.estimate(A, B);
+
+		assert_eq!(7., estimate);
+	}
+
+	#[test]
+	fn octile() {
+		let estimate: f32 = Octile.estimate(A, B);
+
+		assert_eq!(3. * std::f32::consts::SQRT_2 + 1., estimate);
+	}
+
+	#[test]
+	fn euclidean() {
+		let estimate: f32 = Euclidean.estimate(A, B);
+
+		assert_eq!(5., estimate);
+	}
+
+	#[test]
+	fn chebyshev() {
+		let estimate: f32 = Chebyshev.estimate(A, B);
+
+		assert_eq!(4., estimate);
+	}
+
+	#[test]
+	fn zero() {
+		let estimate: f32 = Zero.estimate(A, B);
+
+		assert_eq!(0., estimate);
+	}
+
+	#[test]
+	fn octile_fixed() {
+		assert_eq!(Fixed::SQRT_2.times(3) + Fixed::ONE, Octile.estimate(A, B));
+	}
+
+	#[test]
+	fn euclidean_fixed() {
+		assert_eq!(Fixed::from_steps(5), Euclidean.estimate(A, B));
+	}
+
+	#[test]
+	fn octile_fixed_not_above_euclidean_on_diagonals() {
+		for steps in 1..10_000 {
+			let diagonal = ComputeGridNode::new(steps, steps);
+			let octile: Fixed = Octile.estimate(ComputeGridNode::new(0, 0), diagonal);
+			let euclidean: Fixed = Euclidean.estimate(ComputeGridNode::new(0, 0), diagonal);
+
+			assert!(octile <= euclidean, "{steps}: {octile:?} > {euclidean:?}");
+		}
+	}
+
+	#[test]
+	fn distance_function() {
+		let custom = |a: ComputeGridNode, b: ComputeGridNode| (a.x - b.x) as f32;
+
+		assert_eq!(-3., custom.estimate(A, B));
+	}
+}
@@ -0,0 +1,248 @@
+use super::{Heuristic, Manhattan, NewHeuristic};
+use crate::{
+	components::compute_path_method::a_star::{GScores, OpenList},
+	traits::{
+		computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+		cost::Cost,
+	},
+};
+use std::sync::Arc;
+
+/// ALT heuristic (A*, landmarks and triangle inequality), only admissible for
+/// horizontal and vertical steps of cost 1
+#[derive(Debug, Clone)]
+pub struct Landmarks {
+	nodes: Vec<ComputeGridNode>,
+	distances: Arc<Vec<GScores>>,
+}
+
+impl Landmarks {
+	pub const DEFAULT_COUNT: usize = 4;
+	const NEIGHBORS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+
+	/// Only nodes connected to the first free node of the grid are considered.
+	pub fn pick(grid: ComputeGrid, obstacles: &Obstacles, count: usize) -> Self {
+		let mut nodes = vec![];
+		let mut distances = vec![];
+		let Some(first) = (0..grid.len())
+			.map(|index| grid.node(index))
+			.find(|node| !obstacles.contains(node))
+		else {
+			return Self {
+				nodes,
+				distances: Arc::new(distances),
+			};
+		};
+		let from_first = Self::distances_from(grid, obstacles, first);
+
+		while nodes.len() < count {
+			let farthest = (0..grid.len())
+				.map(|index| grid.node(index))
+				.filter(|node| from_first.get(node).is_finite())
+				.map(|node| (node, Self::min_distance(&distances, &node, &from_first)))
+				.fold(None, |farthest, (node, distance)| match farthest {
+					Some((_, max)) if distance <= max => farthest,
+					_ => Some((node, distance)),
+				});
+
+			let Some((node, distance)) = farthest else {
+				break;
+			};
+			if distance == 0. {
+				break;
+			}
+
+			nodes.push(node);
+			distances.push(Self::distances_from(grid, obstacles, node));
+		}
+
+		Self {
+			nodes,
+			distances: Arc::new(distances),
+		}
+	}
+
+	pub fn nodes(&self) -> &[ComputeGridNode] {
+		&self.nodes
+	}
+
+	fn min_distance(distances: &[GScores], node: &ComputeGridNode, fallback: &GScores) -> f32 {
+		match distances {
+			[] => fallback.get(node),
+			distances => distances
+				.iter()
+				.map(|distances| distances.get(node))
+				.fold(f32::INFINITY, f32::min),
+		}
+	}
+
+	fn distances_from(
+		grid: ComputeGrid,
+		obstacles: &Obstacles,
+		source: ComputeGridNode,
+	) -> GScores {
+		let no_heuristic = |_, _| 0.;
+		let mut open = OpenList::new(source, source, no_heuristic);
+		let mut distances = GScores::new(grid, source);
+
+		while let Some(current) = open.pop_lowest_f() {
+			for (x, y) in Self::NEIGHBORS {
+				let neighbor = ComputeGridNode::new(current.x + x, current.y + y);
+
+				if !grid.contains(neighbor) || obstacles.contains(&neighbor) {
+					continue;
+				}
+
+				let g = distances.get(&current) + 1.;
+
+				if g >= distances.get(&neighbor) {
+					continue;
+				}
+
+				open.push(neighbor, g);
+				distances.insert(neighbor, g);
+			}
+		}
+
+		distances
+	}
+}
+
+impl<TCost> Heuristic<TCost> for Landmarks
+where
+	TCost: Cost,
+{
+	fn estimate(&self, node: ComputeGridNode, end: ComputeGridNode) -> TCost {
+		let steps = self
+			.distances
+			.iter()
+			.map(|distances| (distances.get(&node), distances.get(&end)))
+			.filter(|(a, b)| a.is_finite() && b.is_finite())
+			.map(|(a, b)| (a - b).abs())
+			.fold(Manhattan::distance(node, end), f32::max);
+
+		TCost::from_steps(steps as u32)
+	}
+}
+
+impl NewHeuristic for Landmarks {
+	fn new(grid: ComputeGrid, obstacles: &Obstacles) -> Self {
+		Self::pick(grid, obstacles, Self::DEFAULT_COUNT)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		components::compute_path_method::a_star::AStar,
+		traits::compute_path::{ComputePath, NewComputer},
+	};
+
+	const GRID: ComputeGrid = ComputeGrid {
+		min: ComputeGridNode::new(0, 0),
+		max: ComputeGridNode::new(9, 9),
+	};
+
+	/// Wall along x = 5, open only at the top
+	fn wall() -> Obstacles {
+		Obstacles::from_nodes(GRID, (0..9).map(|y| ComputeGridNode::new(5, y)))
+	}
+
+	#[test]
+	fn pick_count() {
+		let landmarks = Landmarks::pick(GRID, &wall(), 3);
+
+		assert_eq!(3, landmarks.nodes().len());
+	}
+
+	#[test]
+	fn pick_far_apart_corners_on_open_grid() {
+		let landmarks = Landmarks::pick(GRID, &Obstacles::new(GRID), 2);
+
+		assert_eq!(
+			[ComputeGridNode::new(9, 9), ComputeGridNode::new(0, 0)],
+			landmarks.nodes()
+		);
+	}
+
+	#[test]
+	fn pick_no_more_than_free_nodes() {
+		let grid = ComputeGrid {
+			min: ComputeGridNode::new(0, 0),
+			max: ComputeGridNode::new(1, 0),
+		};
+
+		let landmarks = Landmarks::pick(grid, &Obstacles::new(grid), 4);
+
+		assert_eq!(2, landmarks.nodes().len());
+	}
+
+	#[test]
+	fn pick_none_when_all_blocked() {
+		let obstacles = Obstacles::from_nodes(GRID, (0..GRID.len()).map(|i| GRID.node(i)));
+
+		let landmarks = Landmarks::pick(GRID, &obstacles, 4);
+
+		assert!(landmarks.nodes().is_empty());
+	}
+
+	#[test]
+	fn estimate_around_wall() {
+		let landmarks = Landmarks::pick(GRID, &wall(), 4);
+
+		let estimate: f32 =
+			landmarks.estimate(ComputeGridNode::new(4, 0), ComputeGridNode::new(6, 0));
+
+		assert_eq!(20., estimate);
+	}
+
+	#[test]
+	fn pick_default_count_when_new() {
+		let landmarks = Landmarks::new(GRID, &wall());
+
+		assert_eq!(
+			Landmarks::pick(GRID, &wall(), Landmarks::DEFAULT_COUNT).nodes(),
+			landmarks.nodes()
+		);
+	}
+
+	#[test]
+	fn estimate_never_exceeds_shortest_path() {
+		let obstacles = wall();
+		let landmarks = Landmarks::pick(GRID, &obstacles, 4);
+		let a_star = <AStar>::new(GRID, obstacles.clone());
+		let nodes = (0..GRID.len())
+			.map(|i| GRID.node(i))
+			.filter(|node| !obstacles.contains(node))
+			.collect::<Vec<_>>();
+
+		for a in nodes.iter().step_by(7) {
+			for b in &nodes {
+				let steps = a_star.path(*a, *b).len() - 1;
+				let estimate: f32 = landmarks.estimate(*a, *b);
+				assert!(
+					estimate <= steps as f32,
+					"{a:?} -> {b:?}: {estimate} > {steps}"
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn fewer_expansions_than_manhattan() {
+		let start = ComputeGridNode::new(4, 0);
+		let end = ComputeGridNode::new(6, 0);
+
+		let (expanded, path) = <AStar>::new(GRID, wall())
+			.with_heuristic(Landmarks::pick(GRID, &wall(), Landmarks::DEFAULT_COUNT))
+			.path_with_expansions(start, end);
+		let (manhattan_expanded, manhattan_path) =
+			AStar::<Manhattan>::new(GRID, wall()).path_with_expansions(start, end);
+
+		assert!(
+			path.len() == manhattan_path.len() && expanded < manhattan_expanded,
+			"{expanded:?} >= {manhattan_expanded:?}"
+		);
+	}
+}
@@ -0,0 +1,6 @@
+use super::computable_grid::{dense::Obstacles, ComputeGridNode};
+
+pub trait LineOfSight {
+	/// No obstacle blocks the line between `a` and `b`.
+	fn visible(&self, obstacles: &Obstacles, a: ComputeGridNode, b: ComputeGridNode) -> bool;
+}
@@ -0,0 +1,20 @@
+use super::computable_grid::ComputeGridNode;
+
+pub trait SpaceTimeConstraints {
+	/// Moving from `from` at time step `t` to `to` at time step `t + 1` is allowed.
+	/// Waiting is a move where `from` and `to` are the same node.
+	fn allows(&self, from: ComputeGridNode, to: ComputeGridNode, t: u32) -> bool;
+
+	/// Last time step any constraint applies to, after it the grid is static.
+	fn last_constrained(&self) -> u32;
+}
+
+impl SpaceTimeConstraints for () {
+	fn allows(&self, _: ComputeGridNode, _: ComputeGridNode, _: u32) -> bool {
+		true
+	}
+
+	fn last_constrained(&self) -> u32 {
+		0
+	}
+}
@@ -0,0 +1,345 @@
+use project_zyheeda_pathfinding::{
+	components::compute_path_method::{
+		a_star::{AStar, TieBreak},
+		anytime_a_star::AnytimeAStar,
+		bidirectional_a_star::BidirectionalAStar,
+		lazy_theta_star::LazyThetaStar,
+		straight_line::StraightLine,
+		straight_line_wide::StraightLineWide,
+		theta_star::ThetaStar,
+		weighted_a_star::WeightedAStar,
+	},
+	traits::{
+		computable_grid::{dense::Obstacles, ComputeGrid, ComputeGridNode},
+		compute_path::{
+			validity::{Moves, PathValidity},
+			ComputePath,
+			NewComputer,
+		},
+		cost::Fixed,
+		heuristic::{landmarks::Landmarks, Chebyshev, Euclidean, Manhattan, Octile, Zero},
+	},
+};
+use proptest::prelude::*;
+
+#[derive(Debug, Clone)]
+struct Query {
+	grid: ComputeGrid,
+	obstacles: Obstacles,
+	start: ComputeGridNode,
+	end: ComputeGridNode,
+}
+
+impl Query {
+	fn path<TMethod>(&self) -> Vec<ComputeGridNode>
+	where
+		TMethod: NewComputer + ComputePath,
+	{
+		TMethod::new(self.grid, self.obstacles.clone()).path(self.start, self.end)
+	}
+
+	fn validity(&self, moves: Moves) -> PathValidity<'_> {
+		PathValidity {
+			grid: self.grid,
+			obstacles: &self.obstacles,
+			moves,
+		}
+	}
+
+	fn dijkstra(&self) -> Option<usize> {
+		let mut distances = vec![usize::MAX; self.grid.len()];
+		let mut done = vec![false; self.grid.len()];
+		distances[self.grid.index(self.start)?] = 0;
+
+		loop {
+			let current = (0..distances.len())
+				.filter(|index| !done[*index] && distances[*index] != usize::MAX)
+				.min_by_key(|index| distances[*index])?;
+			let node = self.grid.node(current);
+
+			if node == self.end {
+				return Some(distances[current]);
+			}
+			done[current] = true;
+
+			for (x, y) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
+				let neighbor = ComputeGridNode::new(node.x + x, node.y + y);
+				let Some(index) = self.grid.index(neighbor) else {
+					continue;
+				};
+				if self.obstacles.contains(&neighbor) {
+					continue;
+				}
+				distances[index] = distances[index].min(distances[current] + 1);
+			}
+		}
+	}
+}
+
+prop_compose! {
+	fn query()(width in 1..12_i32, height in 1..12_i32)(
+		blocked in prop::collection::vec(prop::bool::weighted(0.3), (width * height) as usize),
+		start in (0..width, 0..height),
+		end in (0..width, 0..height),
+		width in Just(width),
+		height in Just(height),
+	) -> Query {
+		let grid = ComputeGrid {
+			min: ComputeGridNode::new(0, 0),
+			max: ComputeGridNode::new(width - 1, height - 1),
+		};
+		let start = ComputeGridNode::new(start.0, start.1);
+		let end = ComputeGridNode::new(end.0, end.1);
+		let obstacles = (0..grid.len())
+			.filter(|index| blocked[*index])
+			.map(|index| grid.node(index))
+			.filter(|node| node != &start && node != &end);
+
+		Query {
+			grid,
+			obstacles: Obstacles::from_nodes(grid, obstacles),
+			start,
+			end,
+		}
+	}
+}
+
+fn cost(path: &[ComputeGridNode]) -> f32 {
+	path.windows(2)
+		.map(|pair| {
+			let delta = pair[1] - pair[0];
+			((delta.x * delta.x + delta.y * delta.y) as f32).sqrt()
+		})
+		.sum()
+}
+
+proptest! {
+	#[test]
+	fn a_star_path_is_valid(query in query()) {
+		let path = query.path::<AStar>();
+
+		prop_assert_eq!(Ok(()), query.validity(Moves::Straight).check(query.start, query.end, &path));
+	}
+
+	#[test]
+	fn a_star_path_is_optimal(query in query()) {
+		let path = query.path::<AStar>();
+
+		prop_assert_eq!(query.dijkstra(), path.len().checked_sub(1));
+	}
+
+	#[test]
+	fn a_star_with_landmarks_path_is_optimal(query in query()) {
+		let path = query.path::<AStar<Landmarks>>();
+
+		prop_assert_eq!(query.dijkstra(), path.len().checked_sub(1));
+	}
+
+	#[test]
+	fn a_star_path_is_optimal_with_fixed_costs(query in query()) {
+		let path = query.path::<AStar<Manhattan, Fixed>>();
+
+		prop_assert_eq!(query.dijkstra(), path.len().checked_sub(1));
+	}
+
+	#[test]
+	fn a_star_path_is_optimal_with_lower_heuristics(query in query()) {
+		let optimal = query.dijkstra();
+
+		prop_assert_eq!(optimal, query.path::<AStar<Octile>>().len().checked_sub(1));
+		prop_assert_eq!(optimal, query.path::<AStar<Euclidean>>().len().checked_sub(1));
+		prop_assert_eq!(optimal, query.path::<AStar<Chebyshev>>().len().checked_sub(1));
+		prop_assert_eq!(optimal, query.path::<AStar<Zero>>().len().checked_sub(1));
+	}
+
+	#[test]
+	fn a_star_path_is_optimal_with_any_tie_break(query in query()) {
+		let tie_breaks = [TieBreak::LowerNode, TieBreak::HigherG, TieBreak::LowerH, TieBreak::Insertion];
+
+		for tie_break in tie_breaks {
+			let path = <AStar>::new(query.grid, query.obstacles.clone())
+				.with_tie_break(tie_break)
+				.path(query.start, query.end);
+
+			prop_assert_eq!(query.dijkstra(), path.len().checked_sub(1), "{:?}", tie_break);
+		}
+	}
+
+	#[test]
+	fn bidirectional_a_star_path_is_valid(query in query()) {
+		let path = query.path::<BidirectionalAStar>();
+
+		prop_assert_eq!(Ok(()), query.validity(Moves::Straight).check(query.start, query.end, &path));
+	}
+
+	#[test]
+	fn bidirectional_a_star_path_is_optimal(query in query()) {
+		let path = query.path::<BidirectionalAStar>();
+
+		prop_assert_eq!(query.dijkstra(), path.len().checked_sub(1));
+	}
+
+	#[test]
+	fn bidirectional_a_star_with_landmarks_path_is_optimal(query in query()) {
+		let path = query.path::<BidirectionalAStar<Landmarks>>();
+
+		prop_assert_eq!(query.dijkstra(), path.len().checked_sub(1));
+	}
+
+	#[test]
+	fn bidirectional_a_star_path_is_optimal_with_fixed_costs(query in query()) {
+		let path = query.path::<BidirectionalAStar<Manhattan, Fixed>>();
+
+		prop_assert_eq!(query.dijkstra(), path.len().checked_sub(1));
+	}
+
+	#[test]
+	fn weighted_a_star_path_is_valid(query in query()) {
+		let path = query.path::<WeightedAStar>();
+
+		prop_assert_eq!(Ok(()), query.validity(Moves::Straight).check(query.start, query.end, &path));
+	}
+
+	#[test]
+	fn weighted_a_star_path_within_bound(query in query()) {
+		let path = query.path::<WeightedAStar>();
+		let optimal = query.dijkstra().map(|steps| steps as f32 * <WeightedAStar>::DEFAULT_WEIGHT);
+
+		prop_assert_eq!(optimal.is_some(), !path.is_empty());
+		if let Some(optimal) = optimal {
+			prop_assert!(cost(&path) <= optimal, "{} > {}", cost(&path), optimal);
+		}
+	}
+
+	#[test]
+	fn weighted_a_star_path_within_bound_with_fixed_costs(query in query()) {
+		let path = query.path::<WeightedAStar<Manhattan, Fixed>>();
+		let optimal = query.dijkstra().map(|steps| steps as f32 * <WeightedAStar>::DEFAULT_WEIGHT);
+
+		prop_assert_eq!(optimal.is_some(), !path.is_empty());
+		if let Some(optimal) = optimal {
+			prop_assert!(cost(&path) <= optimal, "{} > {}", cost(&path), optimal);
+		}
+	}
+
+	#[test]
+	fn anytime_a_star_path_is_valid(query in query()) {
+		let path = query.path::<AnytimeAStar>();
+
+		prop_assert_eq!(Ok(()), query.validity(Moves::Straight).check(query.start, query.end, &path));
+	}
+
+	#[test]
+	fn anytime_a_star_path_is_optimal(query in query()) {
+		let path = query.path::<AnytimeAStar>();
+
+		prop_assert_eq!(query.dijkstra(), path.len().checked_sub(1));
+	}
+
+	#[test]
+	fn anytime_a_star_with_landmarks_path_is_optimal(query in query()) {
+		let path = query.path::<AnytimeAStar<Landmarks>>();
+
+		prop_assert_eq!(query.dijkstra(), path.len().checked_sub(1));
+	}
+
+	#[test]
+	fn anytime_a_star_path_is_optimal_with_fixed_costs(query in query()) {
+		let path = query.path::<AnytimeAStar<Manhattan, Fixed>>();
+
+		prop_assert_eq!(query.dijkstra(), path.len().checked_sub(1));
+	}
+
+	#[test]
+	fn theta_star_path_is_valid(query in query()) {
+		let path = query.path::<ThetaStar>();
+
+		prop_assert_eq!(Ok(()), query.validity(Moves::LineOfSight).check(query.start, query.end, &path));
+	}
+
+	#[test]
+	fn theta_star_path_is_valid_with_fixed_costs(query in query()) {
+		let path = query.path::<ThetaStar<Octile, Fixed>>();
+
+		prop_assert_eq!(Ok(()), query.validity(Moves::LineOfSight).check(query.start, query.end, &path));
+		prop_assert_eq!(query.dijkstra().is_some(), !path.is_empty());
+	}
+
+	#[test]
+	fn lazy_theta_star_path_is_valid_with_fixed_costs(query in query()) {
+		let path = query.path::<LazyThetaStar<Octile, Fixed>>();
+
+		prop_assert_eq!(Ok(()), query.validity(Moves::LineOfSight).check(query.start, query.end, &path));
+		prop_assert_eq!(query.dijkstra().is_some(), !path.is_empty());
+	}
+
+	#[test]
+	fn theta_star_finds_path_when_reachable(query in query()) {
+		let path = query.path::<ThetaStar>();
+
+		prop_assert_eq!(query.dijkstra().is_some(), !path.is_empty());
+	}
+
+	#[test]
+	fn theta_star_path_not_longer_than_a_star_path(query in query()) {
+		let theta_star = cost(&query.path::<ThetaStar>());
+		let a_star = cost(&query.path::<AStar>());
+
+		prop_assert!(theta_star <= a_star + 0.001, "{} > {}", theta_star, a_star);
+	}
+
+	#[test]
+	fn lazy_theta_star_path_is_valid(query in query()) {
+		let path = query.path::<LazyThetaStar>();
+
+		prop_assert_eq!(Ok(()), query.validity(Moves::LineOfSight).check(query.start, query.end, &path));
+	}
+
+	#[test]
+	fn lazy_theta_star_finds_path_when_reachable(query in query()) {
+		let path = query.path::<LazyThetaStar>();
+
+		prop_assert_eq!(query.dijkstra().is_some(), !path.is_empty());
+	}
+
+	#[test]
+	fn lazy_theta_star_path_not_longer_than_a_star_path(query in query()) {
+		let lazy_theta_star = cost(&query.path::<LazyThetaStar>());
+		let a_star = cost(&query.path::<AStar>());
+
+		prop_assert!(lazy_theta_star <= a_star + 0.001, "{} > {}", lazy_theta_star, a_star);
+	}
+
+	#[test]
+	fn straight_line_is_valid(query in query()) {
+		let path = query.path::<StraightLine>();
+		let no_obstacles = Obstacles::new(query.grid);
+		let validity = PathValidity { obstacles: &no_obstacles, ..query.validity(Moves::Adjacent) };
+
+		prop_assert_eq!(Ok(()), validity.check(query.start, query.end, &path));
+	}
+
+	/// The wide line is unordered, so it is only checked to cover the straight line.
+	#[test]
+	fn straight_line_wide_covers_straight_line(query in query()) {
+		let wide = query.path::<StraightLineWide>();
+		let line = query.path::<StraightLine>();
+
+		prop_assert!(line.iter().all(|node| wide.contains(node)), "{:?} not covering {:?}", wide, line);
+	}
+
+	#[test]
+	fn straight_line_wide_stays_within_bounds(query in query()) {
+		let wide = query.path::<StraightLineWide>();
+		let bounds = ComputeGrid {
+			min: ComputeGridNode::new(query.start.x.min(query.end.x), query.start.y.min(query.end.y)),
+			max: ComputeGridNode::new(query.start.x.max(query.end.x), query.start.y.max(query.end.y)),
+		};
+		let mut unique = wide.clone();
+		unique.sort();
+		unique.dedup();
+
+		prop_assert!(wide.iter().all(|node| bounds.contains(*node)), "{:?} outside of {:?}", wide, bounds);
+		prop_assert_eq!(unique.len(), wide.len());
+	}
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "app")]
+
+use project_zyheeda_pathfinding::dtos::scenario::Scenario;
+use std::{error::Error, path::Path};
+
+/// Runs every scenario in `scenarios/`. Files exported from the editor with `E` can be
+/// copied there as they are.
+#[test]
+fn run_scenarios() -> Result<(), Box<dyn Error>> {
+	let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("scenarios");
+	let files = Scenario::files_in(&directory)?;
+
+	let failures = files
+		.iter()
+		.filter_map(|file| {
+			let result = match Scenario::read(file) {
+				Ok(scenario) => scenario.run().map_err(|error| error.to_string()),
+				Err(error) => Err(error.to_string()),
+			};
+			let error = result.err()?;
+			Some(format!("{}: {}", file.display(), error))
+		})
+		.collect::<Vec<_>>();
+
+	assert!(!files.is_empty(), "no scenarios in {}", directory.display());
+	assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+	Ok(())
+}